@@ -8,6 +8,9 @@ use std::{
     collections::HashMap,
     ffi::{OsStr, OsString},
     path::PathBuf,
+    process::{Command, Stdio},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 use crate::{config::LintDependencyEntry, ExitStatus};
@@ -38,6 +41,15 @@ pub struct Config {
     pub build_rustc_flags: String,
     /// Indicates if this is a release or debug build.
     pub debug_build: bool,
+    /// Indicates if the time spent in each lint crate should be measured.
+    pub timings: bool,
+    /// The format used to report diagnostics.
+    pub output_format: OutputFormat,
+    /// The maximum number of diagnostics reported for each crate. `0` means
+    /// that all diagnostics are reported.
+    pub max_diagnostics: usize,
+    /// The target triple of the host, which lint crates are compiled for.
+    pub host_triple: String,
     pub toolchain: Toolchain,
 }
 
@@ -48,6 +60,10 @@ impl Config {
             lints: HashMap::default(),
             build_rustc_flags: String::new(),
             debug_build: false,
+            timings: false,
+            output_format: OutputFormat::Human,
+            max_diagnostics: 0,
+            host_triple: toolchain::host_triple(toolchain.cargo.toolchain.as_deref())?,
             toolchain,
         })
     }
@@ -61,14 +77,27 @@ impl Config {
     }
 }
 
+/// The format used to report diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// The normal human readable output of rustc.
+    Human,
+    /// Every diagnostic is additionally printed to stdout as a single line
+    /// JSON object.
+    Json,
+}
+
 /// This struct contains all information to use rustc as a driver.
+#[derive(Debug, Clone)]
 pub struct CheckInfo {
     pub env: Vec<(&'static str, OsString)>,
 }
 
 pub fn prepare_check(config: &Config) -> Result<CheckInfo, ExitStatus> {
-    println!();
-    println!("Compiling Lints:");
+    // Status messages are written to stderr, to not interfere with machine
+    // readable output on stdout.
+    eprintln!();
+    eprintln!("Compiling Lints:");
     let lints = lints::build_lints(config)?;
 
     #[rustfmt::skip]
@@ -79,21 +108,46 @@ pub fn prepare_check(config: &Config) -> Result<CheckInfo, ExitStatus> {
     if let Some(toolchain) = &config.toolchain.cargo.toolchain {
         env.push(("RUSTUP_TOOLCHAIN", toolchain.into()));
     }
+    if config.timings {
+        env.push(("MARKER_TIMINGS", "1".into()));
+    }
+    if config.output_format == OutputFormat::Json {
+        env.extend(json_output_env());
+    }
+    if config.max_diagnostics != 0 {
+        env.push(("MARKER_MAX_DIAGNOSTICS", config.max_diagnostics.to_string().into()));
+    }
 
     Ok(CheckInfo { env })
 }
 
-pub fn run_check(config: &Config, info: CheckInfo, additional_cargo_args: &[String]) -> Result<(), ExitStatus> {
-    println!();
-    println!("Start linting:");
+/// Returns the environment values, which make the driver print diagnostics as
+/// JSON to stdout.
+///
+/// Cargo doesn't run the driver for crates, which have already been checked,
+/// instead it replays the cached stderr output. The JSON diagnostics on stdout
+/// would be missing. The `MARKER_RUN_ID` value is tracked by the driver and
+/// unique for every call, which forces Cargo to check the crates again.
+pub fn json_output_env() -> [(&'static str, OsString); 2] {
+    static RUN_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
-    let mut cmd = config.toolchain.cargo_with_driver();
-    cmd.arg("check");
-    cmd.args(additional_cargo_args);
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_nanos());
+    let count = RUN_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let run_id = format!("{}-{time}-{count}", std::process::id());
 
-    cmd.envs(info.env);
+    [
+        ("MARKER_OUTPUT_FORMAT", "json".into()),
+        ("MARKER_RUN_ID", run_id.into()),
+    ]
+}
+
+pub fn run_check(config: &Config, info: CheckInfo, additional_cargo_args: &[String]) -> Result<(), ExitStatus> {
+    eprintln!();
+    eprintln!("Start linting:");
 
-    let exit_status = cmd
+    let exit_status = check_command(config, info, additional_cargo_args)
         .spawn()
         .expect("could not run cargo")
         .wait()
@@ -106,6 +160,70 @@ pub fn run_check(config: &Config, info: CheckInfo, additional_cargo_args: &[Stri
     }
 }
 
+/// Creates the `cargo check` command, which runs the driver with the lint
+/// crates specified in the given [`CheckInfo`].
+pub fn check_command(config: &Config, info: CheckInfo, additional_cargo_args: &[String]) -> Command {
+    let mut cmd = config.toolchain.cargo_with_driver();
+    cmd.arg("check");
+    cmd.args(additional_cargo_args);
+
+    cmd.envs(info.env);
+    cmd
+}
+
+/// Prints the explanation of the given lint.
+pub fn run_explain(config: &Config, info: CheckInfo, lint: &str) -> Result<(), ExitStatus> {
+    run_driver_command(config, info, &["--explain-lint", lint])
+}
+
+/// Prints all lints from the lint crates, organized by group.
+pub fn run_list_lints(config: &Config, info: CheckInfo) -> Result<(), ExitStatus> {
+    run_driver_command(config, info, &["--list-lints"])
+}
+
+/// Runs the driver with the given arguments. The driver has to be invoked via
+/// cargo, to ensure that the libraries are correctly linked.
+fn run_driver_command(config: &Config, info: CheckInfo, driver_args: &[&str]) -> Result<(), ExitStatus> {
+    let mut cmd = config.toolchain.cargo_with_driver();
+    cmd.arg("rustc")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(config.marker_dir.join("Cargo.toml").as_os_str())
+        .arg("--")
+        .args(driver_args);
+
+    cmd.envs(info.env);
+
+    // The stderr output is captured, to hide cargo's messages about the
+    // dummy crate, which is used to invoke the driver.
+    let output = cmd
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::piped())
+        .output()
+        .expect("could not run cargo");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if output.status.success() {
+        eprint!("{stderr}");
+        return Ok(());
+    }
+
+    if let Some(lint) = stderr.lines().find_map(unknown_lint_name) {
+        return Err(ExitStatus::UnknownLint(lint.to_string()));
+    }
+    stderr
+        .lines()
+        .filter(|line| !line.starts_with("error: could not compile"))
+        .for_each(|line| eprintln!("{line}"));
+    Err(ExitStatus::DriverFailed)
+}
+
+/// Returns the lint name, if the given line is the driver's error for an
+/// unknown lint.
+fn unknown_lint_name(line: &str) -> Option<&str> {
+    line.strip_prefix("error: no lint named `")?.split('`').next()
+}
+
 pub fn to_marker_lint_crates_env(lints: &[LintCrate]) -> OsString {
     let lint_paths: Vec<_> = lints
         .iter()
@@ -118,3 +236,29 @@ pub fn to_marker_lint_crates_env(lints: &[LintCrate]) -> OsString {
         .collect();
     lint_paths.join(OsStr::new(";"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_output_env_is_unique_per_run() {
+        let run_id = |env: [(&'static str, OsString); 2]| {
+            env.into_iter()
+                .find(|(name, _)| *name == "MARKER_RUN_ID")
+                .map(|(_, value)| value)
+                .unwrap()
+        };
+
+        let first = json_output_env();
+        assert!(first.contains(&("MARKER_OUTPUT_FORMAT", "json".into())));
+        assert_ne!(run_id(first), run_id(json_output_env()));
+    }
+
+    #[test]
+    fn test_unknown_lint_name() {
+        let line = "error: no lint named `marker::missing` was found in the loaded lint crates";
+        assert_eq!(unknown_lint_name(line), Some("marker::missing"));
+        assert_eq!(unknown_lint_name("error: could not compile `crate`"), None);
+    }
+}
@@ -71,6 +71,22 @@ impl Cargo {
         Ok(manifest_location.root)
     }
 
+    /// Returns the names of all packages, which are members of the current
+    /// workspace.
+    pub fn workspace_package_names(&self) -> Result<Vec<String>, ExitStatus> {
+        let metadata = self
+            .metadata()
+            .no_deps()
+            .exec()
+            .map_err(|err| ExitStatus::fatal(err, "failed to read the workspace metadata"))?;
+
+        Ok(metadata
+            .workspace_packages()
+            .into_iter()
+            .map(|pkg| pkg.name.clone())
+            .collect())
+    }
+
     // Keep self for future changes. It's implemented in such way that clippy
     // doesn't ask to write it as an associative function.
     #[allow(clippy::unused_self)]
@@ -4,7 +4,7 @@ use once_cell::sync::Lazy;
 
 use crate::{utils::is_local_driver, ExitStatus};
 
-use super::toolchain::{get_toolchain_folder, rustup_which, Toolchain};
+use super::toolchain::{get_toolchain_folder, host_triple, rustup_which, Toolchain};
 
 #[cfg(unix)]
 pub const MARKER_DRIVER_BIN_NAME: &str = "marker_rustc_driver";
@@ -15,8 +15,8 @@ pub const MARKER_DRIVER_BIN_NAME: &str = "marker_rustc_driver.exe";
 /// to install the driver.
 pub static DEFAULT_DRIVER_INFO: Lazy<DriverVersionInfo> = Lazy::new(|| DriverVersionInfo {
     toolchain: "nightly-2023-07-13".to_string(),
-    version: "0.1.1".to_string(),
-    api_version: "0.1.1".to_string(),
+    version: "0.2.0-dev".to_string(),
+    api_version: "0.2.0-dev".to_string(),
 });
 
 /// The version info of one specific driver
@@ -146,6 +146,12 @@ fn build_driver(toolchain: &str, version: &str, additional_rustc_flags: &str) ->
         cmd.arg("--root");
         cmd.arg(install_root.as_os_str());
         cmd.arg("--no-track");
+
+        // The driver is run on the host, even if the linted crate is cross-compiled.
+        // Due to the explicit target, `RUSTFLAGS` are not applied to build scripts
+        // and proc-macros. See `Toolchain::cargo_build_command` for more details.
+        cmd.arg("--target");
+        cmd.arg(host_triple(Some(toolchain))?);
     }
     cmd.env("RUSTFLAGS", rustc_flags);
     cmd.env("MARKER_ALLOW_DRIVER_BUILD", "1");
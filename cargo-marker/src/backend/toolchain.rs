@@ -47,7 +47,18 @@ impl Toolchain {
             cmd.arg("--release");
         }
 
+        // Lint crates are loaded by the driver and therefore have to be compiled
+        // for the host, even if the linted crate is cross-compiled
+        cmd.arg("--target");
+        cmd.arg(&config.host_triple);
+
         // Environment
+        //
+        // With an explicit `--target`, even if it's the host, Cargo only applies
+        // `RUSTFLAGS` to the artifacts of the target. Build scripts and proc-macros
+        // are compiled without them. This is fine, since the flags are meant for
+        // the lint crates. Forwarding them to host artifacts would require the
+        // unstable `-Zhost-config` and `-Ztarget-applies-to-host` options.
         cmd.env("RUSTFLAGS", &config.build_rustc_flags);
 
         cmd
@@ -131,6 +142,37 @@ pub(crate) fn get_toolchain_folder(toolchain: &str) -> Result<PathBuf, ExitStatu
     Err(ExitStatus::BadConfiguration)
 }
 
+/// Returns the host target triple of the given toolchain, or of the default
+/// `rustc`, if no toolchain is specified.
+///
+/// The driver and lint crates always have to be compiled for the host, since
+/// the lint crates are loaded by the driver. This triple is passed to Cargo
+/// explicitly, to ignore a `build.target` value from the Cargo config, which is
+/// used when cross-compiling.
+pub(crate) fn host_triple(toolchain: Option<&str>) -> Result<String, ExitStatus> {
+    let mut cmd = if let Some(toolchain) = toolchain {
+        let mut cmd = Command::new("rustup");
+        cmd.args(["run", toolchain, "rustc"]);
+        cmd
+    } else {
+        Command::new("rustc")
+    };
+
+    let output = cmd
+        .arg("-vV")
+        .output()
+        .map_err(|err| ExitStatus::fatal(err, "failed to execute rustc"))?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(|host| host.trim().to_string())
+        .ok_or_else(|| ExitStatus::Fatal {
+            message: "unable to determine the host target triple from `rustc -vV`".to_string(),
+            source: None,
+        })
+}
+
 pub(crate) fn rustup_which(toolchain: &str, tool: &str, verbose: bool) -> Result<PathBuf, ExitStatus> {
     if verbose {
         println!("Searching for `{tool}` with rustup for toolchain `{toolchain}`");
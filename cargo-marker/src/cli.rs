@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
-use camino::Utf8Path;
-use clap::{Args, Parser, Subcommand};
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::backend::OutputFormat;
 
 /// Marker's CLI interface
 ///
@@ -44,6 +46,12 @@ pub enum CliCommand {
     Check(CheckArgs),
     /// Setup the rustc driver for Marker
     Setup(SetupArgs),
+    /// Print the explanation of a lint
+    Explain(ExplainArgs),
+    /// List all lints from the lint crates, organized by group
+    ListLints(ListLintsArgs),
+    /// Create a new lint crate
+    Init(InitArgs),
     /// **UNSTABLE** Setup the specified lint crate for ui tests
     #[command(hide = true)]
     TestSetup(CheckArgs),
@@ -51,6 +59,7 @@ pub enum CliCommand {
 
 #[derive(Args, Debug)]
 #[command(override_usage = "cargo marker check [OPTIONS] -- <CARGO ARGS>")]
+#[allow(clippy::struct_excessive_bools)] // These are independent CLI flags
 pub struct CheckArgs {
     /// Specifies lint crates which should be used. (Lints in `Cargo.toml` will be ignored)
     #[arg(short, long)]
@@ -58,12 +67,59 @@ pub struct CheckArgs {
     /// Forwards the current `RUSTFLAGS` value during driver and lint crate compilation
     #[arg(long)]
     pub forward_rust_flags: bool,
+    /// Prints a summary of the time spent in each lint crate to stderr
+    #[arg(long)]
+    pub timings: bool,
+    /// The format used to report diagnostics. With `json`, every diagnostic
+    /// is additionally printed to stdout as a newline-delimited JSON object
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human)]
+    pub output_format: OutputFormat,
+    /// Applies all machine applicable suggestions and checks the package again
+    #[arg(long)]
+    pub fix: bool,
+    /// Checks all packages in the workspace
+    #[arg(short, long, conflicts_with = "packages")]
+    pub workspace: bool,
+    /// Package to check. This argument can be specified multiple times
+    #[arg(short, long = "package", value_name = "SPEC")]
+    pub packages: Vec<String>,
+    /// Controls when colored output is used. `auto` respects the `NO_COLOR`
+    /// environment value
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto, value_name = "WHEN")]
+    pub color: ColorChoice,
+    /// The maximum number of diagnostics reported for each checked crate, the
+    /// limit doesn't apply across crates. Lints with the `deny` level or higher
+    /// are always reported and not counted. (`0` reports all diagnostics)
+    #[arg(long, default_value_t = 0, value_name = "N")]
+    pub max_diagnostics: usize,
 
     /// Arguments which will be forwarded to Cargo. See `cargo check --help`
     #[clap(last = true)]
     pub cargo_args: Vec<String>,
 }
 
+/// The `--color` options, mirroring the option of Cargo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Returns the value for Cargo's `--color` argument. `None` is returned,
+    /// if Cargo should use its default detection.
+    pub fn to_cargo_arg(self) -> Option<&'static str> {
+        match self {
+            // Cargo doesn't check `NO_COLOR` by itself
+            ColorChoice::Auto if std::env::var_os("NO_COLOR").map_or(false, |val| !val.is_empty()) => Some("never"),
+            ColorChoice::Auto => None,
+            ColorChoice::Always => Some("always"),
+            ColorChoice::Never => Some("never"),
+        }
+    }
+}
+
 #[derive(Args, Debug)]
 pub struct SetupArgs {
     /// Automatically installs the required toolchain using rustup
@@ -74,18 +130,46 @@ pub struct SetupArgs {
     pub forward_rust_flags: bool,
 }
 
+#[derive(Args, Debug)]
+#[command(override_usage = "cargo marker explain [OPTIONS] <LINT>")]
+pub struct ExplainArgs {
+    /// The name of the lint, like `marker::lint_name` or `lint_name`
+    pub lint: String,
+    /// Specifies lint crates which should be used. (Lints in `Cargo.toml` will be ignored)
+    #[arg(short, long)]
+    pub lints: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+#[command(override_usage = "cargo marker list-lints [OPTIONS]")]
+pub struct ListLintsArgs {
+    /// Specifies lint crates which should be used. (Lints in `Cargo.toml` will be ignored)
+    #[arg(short, long)]
+    pub lints: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+#[command(override_usage = "cargo marker init [OPTIONS] <NAME>")]
+pub struct InitArgs {
+    /// The name of the new lint crate
+    pub name: String,
+    /// The directory, where the lint crate should be created. (Defaults to `./<NAME>`)
+    #[arg(long)]
+    pub path: Option<Utf8PathBuf>,
+}
+
 use crate::{
     config::{Config, ConfigFetchError, LintDependency},
     ExitStatus,
 };
 
-pub fn collect_lint_deps(args: &CheckArgs) -> Result<HashMap<String, LintDependency>, ExitStatus> {
-    if args.lints.is_empty() {
+pub fn collect_lint_deps(lint_args: &[String]) -> Result<HashMap<String, LintDependency>, ExitStatus> {
+    if lint_args.is_empty() {
         return Err(ExitStatus::NoLints);
     }
 
     let mut virtual_manifest = "[workspace.metadata.marker.lints]\n".to_string();
-    for dep in &args.lints {
+    for dep in lint_args {
         virtual_manifest.push_str(dep);
         virtual_manifest.push('\n');
     }
@@ -129,5 +213,20 @@ mod tests {
         } else {
             assert!(false, "the `check` subcommand was not detected");
         }
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "explain", "marker::lint_name"]);
+        if let Some(CliCommand::Explain(explain_args)) = cli.command {
+            assert!(explain_args.lint == "marker::lint_name");
+            assert!(explain_args.lints.is_empty());
+        } else {
+            panic!("the `explain` subcommand was not detected");
+        }
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "list-lints"]);
+        assert!(matches!(cli.command, Some(CliCommand::ListLints(_))));
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "--color", "never"]);
+        assert_eq!(cli.check_args.color, ColorChoice::Never);
+        assert_eq!(ColorChoice::Always.to_cargo_arg(), Some("always"));
     }
 }
@@ -1,5 +1,6 @@
 //! This module is responsible for translating the `[workspace.metadata.marker]`
-//! section in `Cargo.toml` files.
+//! section in `Cargo.toml` files. The same configuration can also be placed in
+//! a separate `.marker.toml` file next to the workspace `Cargo.toml` file.
 //!
 //! The TOML format specifies that every TOML file must be a valid UTF-8.
 //! ([source](https://toml.io/en/v1.0.0)) This allows Marker to just use
@@ -12,6 +13,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::ExitStatus;
 
+/// The name of the separate config file. It's expected to be in the same
+/// directory as the workspace `Cargo.toml` file.
+pub const MARKER_TOML: &str = ".marker.toml";
+
 #[derive(Deserialize, Debug)]
 struct CargoToml {
     workspace: Option<Workspace>,
@@ -27,7 +32,8 @@ struct WorkspaceMetadata {
     marker: Option<Config>,
 }
 
-/// Markers metadata section `workspace.metadata.marker` in `Cargo.toml`
+/// Markers metadata section `workspace.metadata.marker` in `Cargo.toml` or the
+/// content of the `.marker.toml` file.
 #[derive(Deserialize, Debug)]
 pub struct Config {
     /// A list of lints.
@@ -122,7 +128,7 @@ pub enum GitRef {
 pub enum ConfigFetchError {
     /// Read failed
     IoError(io::Error),
-    /// Couldn't parse `Cargo.toml`
+    /// Couldn't parse `Cargo.toml` or `.marker.toml`
     ParseError(toml::de::Error),
     /// `workspace.metadata.marker` doesn't exist
     SectionNotFound,
@@ -142,12 +148,41 @@ impl ConfigFetchError {
 }
 
 impl Config {
+    /// Loads the config for the workspace of the given `Cargo.toml` file. A
+    /// `.marker.toml` file next to the manifest is preferred over the
+    /// `[workspace.metadata.marker]` section of the manifest.
     pub fn try_from_manifest(path: &Utf8Path) -> Result<Config, ConfigFetchError> {
         let config_str = fs::read_to_string(path).map_err(ConfigFetchError::IoError)?;
 
+        let marker_toml = path.with_file_name(MARKER_TOML);
+        if marker_toml.is_file() {
+            if Self::try_from_str(&config_str, path).is_ok() {
+                eprintln!(
+                    "warning: Marker is configured in `{marker_toml}` and `{path}`, \
+                    the config in `{MARKER_TOML}` will be used"
+                );
+            }
+
+            let marker_str = fs::read_to_string(&marker_toml).map_err(ConfigFetchError::IoError)?;
+            return Self::try_from_marker_toml_str(&marker_str, &marker_toml);
+        }
+
         Self::try_from_str(&config_str, path)
     }
 
+    /// Parses the content of a `.marker.toml` file. The config is expected at
+    /// the top level, without the `workspace.metadata.marker` prefix.
+    fn try_from_marker_toml_str(config_str: &str, path: &Utf8Path) -> Result<Config, ConfigFetchError> {
+        let mut config: Config = toml::from_str(config_str).map_err(ConfigFetchError::ParseError)?;
+
+        let workspace_path = path
+            .parent()
+            .expect("path must have a parent after reading the `.marker.toml` file");
+        config.normalize(workspace_path)?;
+
+        Ok(config)
+    }
+
     pub(crate) fn try_from_str(config_str: &str, path: &Utf8Path) -> Result<Config, ConfigFetchError> {
         let cargo_toml: CargoToml = toml::from_str(config_str).map_err(ConfigFetchError::ParseError)?;
 
@@ -176,3 +211,26 @@ impl Config {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marker_toml_config() {
+        let config =
+            Config::try_from_marker_toml_str("[lints]\nmarker_lints = \"0.1.1\"\n", Utf8Path::new("./.marker.toml"))
+                .unwrap();
+        assert!(matches!(
+            config.lints.get("marker_lints"),
+            Some(LintDependency::Simple(version)) if version == "0.1.1"
+        ));
+
+        // The `workspace.metadata.marker` prefix is only used in `Cargo.toml`
+        let config = Config::try_from_marker_toml_str(
+            "[workspace.metadata.marker.lints]\nmarker_lints = \"0.1.1\"\n",
+            Utf8Path::new("./.marker.toml"),
+        );
+        assert!(matches!(config, Err(ConfigFetchError::ParseError(_))));
+    }
+}
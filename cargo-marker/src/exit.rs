@@ -59,6 +59,8 @@ pub enum ExitStatus {
     DriverInstallationFailed,
     /// A general collection status, for failures originating from the driver
     DriverFailed,
+    /// The driver couldn't find the given lint in the lint crates
+    UnknownLint(String),
     /// The lint crate build failed for some reason
     LintCrateBuildFail,
     /// Lint crate could not be found
@@ -111,6 +113,11 @@ impl Debug for ExitStatus {
                 HELP_INSTALL_DRIVER_FAILED.replace("{{toolchain}}", &DEFAULT_DRIVER_INFO.toolchain)
             ),
             Self::DriverFailed => write!(f, "DriverFailed"),
+            Self::UnknownLint(lint) => write!(
+                f,
+                "no lint named `{lint}` was found in the loaded lint crates\n\n\
+                * Try `cargo marker list-lints` to see all available lints"
+            ),
             Self::LintCrateBuildFail => write!(f, "LintCrateBuildFail"),
             Self::LintCrateNotFound => write!(f, "LintCrateNotFound"),
             Self::LintCrateLibNotFound => write!(f, "LintCrateLibNotFound"),
@@ -0,0 +1,377 @@
+//! Applies machine applicable suggestions emitted by lint crates.
+//!
+//! The suggestions are collected from the JSON diagnostics, which the adapter
+//! prints to stdout, if `MARKER_OUTPUT_FORMAT` is set to `json`.
+
+use std::{
+    collections::BTreeMap,
+    io::{BufRead, BufReader},
+    path::PathBuf,
+    process::Stdio,
+};
+
+use serde::Deserialize;
+
+use crate::{
+    backend::{self, CheckInfo, Config},
+    ExitStatus,
+};
+
+#[derive(Deserialize)]
+struct JsonDiagnostic {
+    suggestions: Vec<JsonSuggestion>,
+}
+
+#[derive(Deserialize)]
+struct JsonSuggestion {
+    applicability: String,
+    parts: Vec<JsonSuggestionPart>,
+}
+
+#[derive(Deserialize)]
+struct JsonSuggestionPart {
+    file: Option<PathBuf>,
+    byte_start: Option<usize>,
+    byte_end: Option<usize>,
+    replacement: String,
+}
+
+/// A single text replacement in a file. The range is given in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Replacement {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+impl Replacement {
+    fn conflicts_with(&self, other: &Replacement) -> bool {
+        // Two insertions at the same position are also considered a conflict,
+        // since the correct order is unknown.
+        (self.start < other.end && other.start < self.end) || self.start == other.start
+    }
+}
+
+/// Collects the machine applicable suggestions of diagnostics and applies them
+/// to the source files.
+///
+/// A suggestion is only accepted, if none of its replacements overlap with the
+/// replacements of a previously accepted suggestion. Duplicate suggestions,
+/// which can for example be emitted for code in generic contexts, are only
+/// applied once.
+#[derive(Debug, Default)]
+pub struct FixSession {
+    files: BTreeMap<PathBuf, Vec<Replacement>>,
+    conflicts: usize,
+}
+
+impl FixSession {
+    /// Adds the machine applicable suggestions of the JSON diagnostic in the
+    /// given line. Lines which don't contain a JSON diagnostic are ignored.
+    pub fn add_json_line(&mut self, line: &str) {
+        let Ok(diag) = serde_json::from_str::<JsonDiagnostic>(line) else {
+            return;
+        };
+
+        for sugg in diag.suggestions {
+            if sugg.applicability == "MachineApplicable" {
+                self.add_suggestion(sugg.parts);
+            }
+        }
+    }
+
+    fn add_suggestion(&mut self, parts: Vec<JsonSuggestionPart>) {
+        // Parts without a file and byte range originate from macros. These
+        // suggestions can't be applied to the source code.
+        let Some(parts) = parts
+            .into_iter()
+            .map(|part| {
+                let replacement = Replacement {
+                    start: part.byte_start?,
+                    end: part.byte_end?,
+                    text: part.replacement,
+                };
+                Some((part.file?, replacement))
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return;
+        };
+
+        let is_duplicate = parts
+            .iter()
+            .all(|(file, repl)| self.files.get(file).map_or(false, |repls| repls.contains(repl)));
+        if is_duplicate {
+            return;
+        }
+
+        let has_conflict = parts.iter().any(|(file, repl)| {
+            self.files
+                .get(file)
+                .map_or(false, |repls| repls.iter().any(|other| other.conflicts_with(repl)))
+        });
+        if has_conflict {
+            self.conflicts += 1;
+            return;
+        }
+
+        for (file, repl) in parts {
+            self.files.entry(file).or_default().push(repl);
+        }
+    }
+
+    /// Applies all collected replacements to the files. Replacements are
+    /// applied in reverse order, to keep the byte ranges of the remaining
+    /// replacements valid.
+    ///
+    /// The original content of the modified files is kept in the returned
+    /// [`FixSummary`]. If a file can't be modified, all previously modified
+    /// files are restored.
+    pub fn apply(self) -> Result<FixSummary, ExitStatus> {
+        let mut summary = FixSummary {
+            files: Vec::with_capacity(self.files.len()),
+            conflicts: self.conflicts,
+        };
+        for (path, repls) in self.files {
+            if let Err(err) = summary.apply_file(path, repls) {
+                summary.restore()?;
+                return Err(err);
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+/// A file, which has been modified by [`FixSession::apply`].
+#[derive(Debug)]
+struct FixedFile {
+    path: PathBuf,
+    /// The number of applied replacements
+    replacements: usize,
+    /// The content of the file, before the replacements were applied
+    original: String,
+}
+
+/// The result of [`FixSession::apply`].
+#[derive(Debug)]
+pub struct FixSummary {
+    files: Vec<FixedFile>,
+    /// The number of suggestions, which were skipped due to conflicts.
+    conflicts: usize,
+}
+
+impl FixSummary {
+    fn apply_file(&mut self, path: PathBuf, mut repls: Vec<Replacement>) -> Result<(), ExitStatus> {
+        let original = std::fs::read_to_string(&path)
+            .map_err(|err| ExitStatus::fatal(err, format!("unable to read `{}`", path.display())))?;
+
+        let mut content = original.clone();
+        repls.sort_by_key(|repl| std::cmp::Reverse((repl.start, repl.end)));
+        for repl in &repls {
+            if repl.end > content.len() || !content.is_char_boundary(repl.start) || !content.is_char_boundary(repl.end)
+            {
+                return Err(ExitStatus::Fatal {
+                    message: format!("the suggestions for `{}` are outdated", path.display()),
+                    source: None,
+                });
+            }
+            content.replace_range(repl.start..repl.end, &repl.text);
+        }
+
+        // The file is added before writing it, to also restore it, if the
+        // write only succeeded partially.
+        let write_result = std::fs::write(&path, content);
+        self.files.push(FixedFile {
+            path,
+            replacements: repls.len(),
+            original,
+        });
+        write_result.map_err(|err| {
+            let path = &self.files.last().unwrap().path;
+            ExitStatus::fatal(err, format!("unable to write `{}`", path.display()))
+        })
+    }
+
+    /// Restores the original content of all modified files.
+    pub fn restore(&self) -> Result<(), ExitStatus> {
+        for file in &self.files {
+            std::fs::write(&file.path, &file.original)
+                .map_err(|err| ExitStatus::fatal(err, format!("unable to restore `{}`", file.path.display())))?;
+        }
+        Ok(())
+    }
+
+    pub fn print(&self) {
+        eprintln!();
+        if self.files.is_empty() {
+            eprintln!("No suggestions were applied");
+        } else {
+            eprintln!("Applied suggestions:");
+            for file in &self.files {
+                eprintln!("    {} ({} replacements)", file.path.display(), file.replacements);
+            }
+        }
+
+        if self.conflicts > 0 {
+            eprintln!(
+                "Skipped {} suggestions, which conflict with other suggestions",
+                self.conflicts
+            );
+        }
+    }
+}
+
+/// Runs Marker to collect the machine applicable suggestions, applies them and
+/// checks the package again, to show the remaining diagnostics.
+pub fn run_fix(config: &Config, info: CheckInfo, additional_cargo_args: &[String]) -> Result<(), ExitStatus> {
+    eprintln!();
+    eprintln!("Collecting suggestions:");
+
+    let json_info = collection_info(&info);
+
+    // The diagnostics of this run are not shown, since the package is checked
+    // again, once the suggestions have been applied.
+    let mut child = backend::check_command(config, json_info, additional_cargo_args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("could not run cargo");
+
+    let mut session = FixSession::default();
+    let stdout = child.stdout.take().expect("stdout is piped");
+    for line in BufReader::new(stdout).lines() {
+        let line = line.map_err(|err| ExitStatus::fatal(err, "unable to read the output of cargo"))?;
+        session.add_json_line(&line);
+    }
+    let status = child.wait().expect("failed to wait for cargo?");
+    if !status.success() {
+        return Err(ExitStatus::Fatal {
+            message: "collecting the suggestions failed, run `cargo marker` without `--fix` to see the errors"
+                .to_string(),
+            source: None,
+        });
+    }
+
+    let summary = session.apply()?;
+    summary.print();
+
+    // Like `cargo fix`, the original files are restored, if the check fails
+    // after the suggestions have been applied.
+    if let Err(err) = backend::run_check(config, info, additional_cargo_args) {
+        if !summary.files.is_empty() {
+            summary.restore()?;
+            eprintln!();
+            eprintln!("The check failed after applying the suggestions, the modified files have been restored");
+        }
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Returns the [`CheckInfo`] for the run collecting the suggestions. All
+/// suggestions should be collected, the diagnostic limit only applies to the
+/// diagnostics shown to the user.
+fn collection_info(info: &CheckInfo) -> CheckInfo {
+    let mut json_info = info.clone();
+    json_info.env.retain(|(name, _)| {
+        !matches!(
+            *name,
+            "MARKER_OUTPUT_FORMAT" | "MARKER_RUN_ID" | "MARKER_MAX_DIAGNOSTICS"
+        )
+    });
+    json_info.env.extend(backend::json_output_env());
+    json_info
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sugg_line(app: &str, parts: &[(&str, usize, usize, &str)]) -> String {
+        let parts: Vec<_> = parts
+            .iter()
+            .map(|(file, start, end, text)| {
+                serde_json::json!({
+                    "file": file,
+                    "byte_start": start,
+                    "byte_end": end,
+                    "replacement": text,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "lint_name": "marker::test",
+            "suggestions": [{ "message": "", "applicability": app, "parts": parts }],
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_collection_info() {
+        let info = CheckInfo {
+            env: vec![
+                ("MARKER_LINT_CRATES", "lints:/lints.so".into()),
+                ("MARKER_MAX_DIAGNOSTICS", "3".into()),
+            ],
+        };
+
+        let first = collection_info(&info);
+        let names: Vec<_> = first.env.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["MARKER_LINT_CRATES", "MARKER_OUTPUT_FORMAT", "MARKER_RUN_ID"]);
+
+        // Every collection run has to be checked again by Cargo
+        let second = collection_info(&first);
+        assert_ne!(first.env[2], second.env[2]);
+    }
+
+    #[test]
+    fn test_fix_session_conflicts() {
+        let mut session = FixSession::default();
+        session.add_json_line("Checking crate");
+        session.add_json_line(&sugg_line("MachineApplicable", &[("a.rs", 0, 4, "new")]));
+        session.add_json_line(&sugg_line("MaybeIncorrect", &[("a.rs", 10, 12, "no")]));
+        // Duplicate
+        session.add_json_line(&sugg_line("MachineApplicable", &[("a.rs", 0, 4, "new")]));
+        // Overlap
+        session.add_json_line(&sugg_line("MachineApplicable", &[("a.rs", 2, 6, "other")]));
+        // The entire multipart suggestion is skipped
+        session.add_json_line(&sugg_line(
+            "MachineApplicable",
+            &[("b.rs", 0, 1, "b"), ("a.rs", 3, 3, "a")],
+        ));
+        session.add_json_line(&sugg_line("MachineApplicable", &[("a.rs", 4, 4, "!")]));
+
+        assert_eq!(session.conflicts, 2);
+        assert!(!session.files.contains_key(&PathBuf::from("b.rs")));
+        let repls = &session.files[&PathBuf::from("a.rs")];
+        assert_eq!(repls.len(), 2);
+    }
+
+    #[test]
+    fn test_fix_session_apply_and_restore() {
+        let dir = std::env::temp_dir().join(format!("marker-fix-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("lib.rs");
+        std::fs::write(&file, "let x = 1;").unwrap();
+        let file_str = file.to_str().unwrap();
+
+        let mut session = FixSession::default();
+        session.add_json_line(&sugg_line("MachineApplicable", &[(file_str, 4, 5, "y")]));
+        session.add_json_line(&sugg_line("MachineApplicable", &[(file_str, 8, 9, "2")]));
+        let summary = session.apply().unwrap();
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "let y = 2;");
+
+        summary.restore().unwrap();
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "let x = 1;");
+
+        // Outdated suggestions don't modify any file
+        let mut session = FixSession::default();
+        session.add_json_line(&sugg_line("MachineApplicable", &[(file_str, 4, 5, "y")]));
+        session.add_json_line(&sugg_line("MachineApplicable", &[(file_str, 20, 21, "z")]));
+        assert!(session.apply().is_err());
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "let x = 1;");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
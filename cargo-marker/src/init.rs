@@ -0,0 +1,120 @@
+//! Scaffolding of new lint crates, used by `cargo marker init`.
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+use crate::{backend::driver::DEFAULT_DRIVER_INFO, utils::is_local_driver, ExitStatus};
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("../templates/init/Cargo.toml.in");
+const LIB_RS_TEMPLATE: &str = include_str!("../templates/init/lib.rs.in");
+
+/// Creates a new lint crate with the given name. The crate is created in a
+/// new directory, which defaults to `./<name>`.
+pub fn init_lint_crate(name: &str, path: Option<&Utf8Path>) -> Result<(), ExitStatus> {
+    validate_crate_name(name)?;
+
+    let dir = path.map_or_else(|| Utf8PathBuf::from(name), Utf8Path::to_path_buf);
+    if dir.exists() {
+        return Err(ExitStatus::Fatal {
+            message: format!("the destination `{dir}` already exists"),
+            source: None,
+        });
+    }
+
+    let crate_name = name.replace('-', "_");
+    let cargo_toml = CARGO_TOML_TEMPLATE
+        .replace("{{crate_name}}", name)
+        .replace("{{marker_api_dependency}}", &marker_api_dependency());
+    let lib_rs = LIB_RS_TEMPLATE.replace("{{lint_pass_name}}", &lint_pass_name(&crate_name));
+
+    let src_dir = dir.join("src");
+    std::fs::create_dir_all(&src_dir)
+        .map_err(|err| ExitStatus::fatal(err, format!("unable to create the directory `{src_dir}`")))?;
+    write_file(&dir.join("Cargo.toml"), &cargo_toml)?;
+    write_file(&src_dir.join("lib.rs"), &lib_rs)?;
+
+    let dir = dir.canonicalize_utf8().unwrap_or(dir);
+    println!("Created lint crate `{name}` at `{dir}`");
+    println!();
+    println!("To use it, add the crate to the `Cargo.toml` of the project you want to lint:");
+    println!();
+    println!("    [workspace.metadata.marker.lints]");
+    println!("    {name} = {{ path = '{dir}' }}");
+    println!();
+    println!("And run `cargo marker` in that project.");
+
+    Ok(())
+}
+
+/// Returns the `marker_api` dependency of the new lint crate. Dev builds use
+/// the `marker_api` crate of this repository, since the API version of a dev
+/// build is not published on crates.io.
+fn marker_api_dependency() -> String {
+    if is_local_driver() {
+        let api_dir = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("../marker_api");
+        let api_dir = api_dir.canonicalize_utf8().unwrap_or(api_dir);
+        format!("{{ path = '{api_dir}' }}")
+    } else {
+        format!("\"{}\"", DEFAULT_DRIVER_INFO.api_version)
+    }
+}
+
+fn write_file(path: &Utf8Path, content: &str) -> Result<(), ExitStatus> {
+    std::fs::write(path, content).map_err(|err| ExitStatus::fatal(err, format!("unable to write `{path}`")))
+}
+
+fn validate_crate_name(name: &str) -> Result<(), ExitStatus> {
+    let is_valid = name
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if is_valid {
+        Ok(())
+    } else {
+        Err(ExitStatus::Fatal {
+            message: format!(
+                "`{name}` is not a valid crate name, it has to start with a letter or `_` and can only contain ASCII letters, numbers, `-` and `_`"
+            ),
+            source: None,
+        })
+    }
+}
+
+/// Converts the crate name into a `CamelCase` lint pass name, like
+/// `my_lints` -> `MyLintsLintPass`.
+fn lint_pass_name(crate_name: &str) -> String {
+    let mut name: String = crate_name
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    name.push_str("LintPass");
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint_pass_name() {
+        assert_eq!(lint_pass_name("my_lints"), "MyLintsLintPass");
+        assert_eq!(lint_pass_name("_lints__v2"), "LintsV2LintPass");
+        assert_eq!(lint_pass_name("lints"), "LintsLintPass");
+    }
+
+    #[test]
+    fn test_validate_crate_name() {
+        assert!(validate_crate_name("my-lints").is_ok());
+        assert!(validate_crate_name("_lints").is_ok());
+        assert!(validate_crate_name("2lints").is_err());
+        assert!(validate_crate_name("my lints").is_err());
+        assert!(validate_crate_name("").is_err());
+    }
+}
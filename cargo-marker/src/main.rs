@@ -7,12 +7,14 @@ mod backend;
 mod cli;
 mod config;
 mod exit;
+mod fix;
+mod init;
 mod utils;
 
 use std::{collections::HashMap, ffi::OsString};
 
 use backend::CheckInfo;
-use cli::{CheckArgs, CliCommand, MarkerCli};
+use cli::{CheckArgs, CliCommand, ExplainArgs, ListLintsArgs, MarkerCli};
 use config::Config;
 
 pub use exit::ExitStatus;
@@ -23,6 +25,11 @@ use crate::backend::driver::DriverVersionInfo;
 fn main() -> Result<(), ExitStatus> {
     let cli = MarkerCli::parse_args();
 
+    // Creating a lint crate doesn't require a Cargo project
+    if let Some(CliCommand::Init(args)) = &cli.command {
+        return init::init_lint_crate(&args.name, args.path.as_deref());
+    }
+
     let cargo = backend::cargo::Cargo::default();
 
     let path = cargo.cargo_locate_project()?;
@@ -43,6 +50,9 @@ fn main() -> Result<(), ExitStatus> {
             };
             backend::driver::install_driver(args.auto_install_toolchain, &rustc_flags)
         },
+        Some(CliCommand::Explain(args)) => run_explain(args, config),
+        Some(CliCommand::ListLints(args)) => run_list_lints(args, config),
+        Some(CliCommand::Init(_)) => unreachable!("handled before locating the project"),
         Some(CliCommand::Check(args)) => run_check(args, config, CheckKind::Normal),
         Some(CliCommand::TestSetup(args)) => run_check(args, config, CheckKind::TestSetup),
         None => run_check(&cli.check_args, config, CheckKind::Normal),
@@ -56,8 +66,93 @@ enum CheckKind {
 }
 
 fn run_check(args: &CheckArgs, config: Option<Config>, kind: CheckKind) -> Result<(), ExitStatus> {
+    let backend_conf = backend::Config {
+        timings: args.timings,
+        output_format: args.output_format,
+        max_diagnostics: args.max_diagnostics,
+        ..create_backend_config(&args.lints, config)?
+    };
+    let cargo_args = check_cargo_args(args, &backend_conf)?;
+
+    // Prepare backend
+    let info = backend::prepare_check(&backend_conf)?;
+
+    // Run backend
+    match kind {
+        CheckKind::Normal if args.fix => fix::run_fix(&backend_conf, info, &cargo_args),
+        CheckKind::Normal => backend::run_check(&backend_conf, info, &cargo_args),
+        CheckKind::TestSetup => {
+            print_test_info(&backend_conf, &info).unwrap();
+            Ok(())
+        },
+    }
+}
+
+/// Translates the color and package selection of the [`CheckArgs`] into Cargo
+/// arguments and adds the arguments, which should be forwarded to Cargo. The
+/// selected packages are validated first, to provide a clean error message.
+fn check_cargo_args(args: &CheckArgs, config: &backend::Config) -> Result<Vec<String>, ExitStatus> {
+    let mut cargo_args = vec![];
+    if let Some(color) = args.color.to_cargo_arg() {
+        cargo_args.push("--color".to_string());
+        cargo_args.push(color.to_string());
+    }
+    if args.workspace {
+        cargo_args.push("--workspace".to_string());
+    }
+
+    if !args.packages.is_empty() {
+        let members = config.toolchain.cargo.workspace_package_names()?;
+        let is_member = |name: &str| members.iter().any(|member| member == name);
+        for spec in &args.packages {
+            if let Some(name) = spec_package_name(spec).filter(|name| !is_member(name)) {
+                return Err(ExitStatus::Fatal {
+                    message: format!(
+                        "the package `{name}` is not a member of the workspace\nAvailable packages: {}",
+                        members.join(", ")
+                    ),
+                    source: None,
+                });
+            }
+
+            cargo_args.push("--package".to_string());
+            cargo_args.push(spec.clone());
+        }
+    }
+
+    cargo_args.extend(args.cargo_args.iter().cloned());
+    Ok(cargo_args)
+}
+
+/// Returns the package name of the given package ID spec, if it should be
+/// validated. Cargo also accepts specs with a version, like `name@1.0.0`, glob
+/// patterns and URLs, like `https://github.com/rust-lang/cargo#0.52.0`. Only
+/// the name of simple specs is returned.
+fn spec_package_name(spec: &str) -> Option<&str> {
+    if spec.contains("://") || spec.contains('#') {
+        return None;
+    }
+
+    let name = spec.split(['@', ':']).next().unwrap_or(spec);
+    let is_pattern = name.contains(['*', '?', '[']);
+    (!is_pattern).then_some(name)
+}
+
+fn run_explain(args: &ExplainArgs, config: Option<Config>) -> Result<(), ExitStatus> {
+    let backend_conf = create_backend_config(&args.lints, config)?;
+    let info = backend::prepare_check(&backend_conf)?;
+    backend::run_explain(&backend_conf, info, &args.lint)
+}
+
+fn run_list_lints(args: &ListLintsArgs, config: Option<Config>) -> Result<(), ExitStatus> {
+    let backend_conf = create_backend_config(&args.lints, config)?;
+    let info = backend::prepare_check(&backend_conf)?;
+    backend::run_list_lints(&backend_conf, info)
+}
+
+fn create_backend_config(lint_args: &[String], config: Option<Config>) -> Result<backend::Config, ExitStatus> {
     // determine lints
-    let deps = match cli::collect_lint_deps(args) {
+    let deps = match cli::collect_lint_deps(lint_args) {
         Ok(deps) => deps,
         Err(ExitStatus::NoLints) => {
             if let Some(config) = config {
@@ -87,22 +182,10 @@ fn run_check(args: &CheckArgs, config: Option<Config>, kind: CheckKind) -> Resul
     // FIXME(xFrednet): Implement better logging and remove verbose boolean in
     // favor of debug logging.
     let toolchain = backend::toolchain::Toolchain::try_find_toolchain(false)?;
-    let backend_conf = backend::Config {
+    Ok(backend::Config {
         lints,
         ..backend::Config::try_base_from(toolchain)?
-    };
-
-    // Prepare backend
-    let info = backend::prepare_check(&backend_conf)?;
-
-    // Run backend
-    match kind {
-        CheckKind::Normal => backend::run_check(&backend_conf, info, &args.cargo_args),
-        CheckKind::TestSetup => {
-            print_test_info(&backend_conf, &info).unwrap();
-            Ok(())
-        },
-    }
+    })
 }
 
 fn print_test_info(config: &backend::Config, check: &CheckInfo) -> Result<(), ExitStatus> {
@@ -157,3 +240,19 @@ fn print_env(env: &[(&'static str, OsString)]) -> std::io::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::spec_package_name;
+
+    #[test]
+    fn test_spec_package_name() {
+        assert_eq!(spec_package_name("marker_api"), Some("marker_api"));
+        assert_eq!(spec_package_name("marker_api@0.1.0"), Some("marker_api"));
+        assert_eq!(spec_package_name("marker_api:0.1.0"), Some("marker_api"));
+        assert_eq!(spec_package_name("marker_*"), None);
+        assert_eq!(spec_package_name("https://github.com/rust-lang/cargo#0.52.0"), None);
+        assert_eq!(spec_package_name("file:///path/to/marker_api#0.1.0"), None);
+        assert_eq!(spec_package_name("marker_api#0.1.0"), None);
+    }
+}
@@ -0,0 +1,37 @@
+//! Checks that a lint crate created by `cargo marker init` can be loaded by
+//! Marker, by listing the lints of the new crate.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_init_crate_can_be_loaded() {
+    let cargo_marker = env!("CARGO_BIN_EXE_cargo-marker");
+    let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let tmp_dir = std::env::temp_dir().join(format!("marker-init-test-{}", std::process::id()));
+    let crate_dir = tmp_dir.join("init_test_lints");
+
+    let status = Command::new(cargo_marker)
+        .args(["marker", "init", "init_test_lints", "--path"])
+        .arg(&crate_dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "`cargo marker init` failed");
+
+    // The lints are listed from the workspace root, to use the local driver
+    let output = Command::new(cargo_marker)
+        .current_dir(workspace_dir)
+        .args(["marker", "list-lints", "--lints"])
+        .arg(format!("init_test_lints = {{ path = '{}' }}", crate_dir.display()))
+        .output()
+        .unwrap();
+    std::fs::remove_dir_all(&tmp_dir).unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "`cargo marker list-lints` failed:\n{stdout}\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("foo_items"), "the lint wasn't listed:\n{stdout}");
+}
@@ -1,13 +1,17 @@
 // The lifetimes are destroyed by unsafe, but help with readability
 #![allow(clippy::needless_lifetimes)]
 
+use crate::{limit, output};
+
 use marker_api::{
     ast::{
-        item::{Body, ItemKind},
-        ty::SemTyKind,
-        BodyId, ExprId, ItemId, Span, SpanId, SymbolId, TyDefId,
+        expr::ExprKind,
+        item::{Body, ItemKind, VisibilityKind},
+        ty::{SemAdtTy, SemTyKind},
+        Attr, BodyId, ConstValue, ExprId, FilePos, ItemId, LineBounds, MacroInfo, MacroOrigin, Span, SpanId,
+        SpanSource, SpanSrcId, SymbolId, TyDefId,
     },
-    context::DriverCallbacks,
+    context::{DriverCallbacks, ItemDeprecation, KnownTrait},
     diagnostic::{Diagnostic, EmissionNode},
     ffi::{self, FfiOption},
     lint::{Level, Lint},
@@ -42,13 +46,30 @@ impl<'ast> DriverContextWrapper<'ast> {
             lint_level_at,
             emit_diag,
             item,
+            parent_item,
+            all_items,
+            macro_info,
+            item_visibility,
+            definition_span,
+            resolve_item_path,
             body,
+            expr,
+            callee_of,
+            resolve_const_value,
+            item_deprecation,
+            item_attrs,
             resolve_ty_ids,
+            type_implements_trait,
+            trait_impls,
+            check_trait_impl,
             expr_ty,
+            field_ty_of,
             span,
             span_snippet,
+            span_macro_origin,
+            span_pos_to_file_pos,
+            span_pos_to_line_bounds,
             symbol_str,
-            resolve_method_target,
         }
     }
 }
@@ -59,8 +80,15 @@ extern "C" fn lint_level_at(data: &(), lint: &'static Lint, node: EmissionNode)
     unsafe { as_driver_cx(data) }.lint_level_at(lint, node)
 }
 
-extern "C" fn emit_diag<'a, 'ast>(data: &'ast (), diag: &Diagnostic<'a, 'ast>) {
-    unsafe { as_driver_cx(data) }.emit_diag(diag);
+extern "C" fn emit_diag<'a, 'ast>(data: &'ast (), diag: &Diagnostic<'a, 'ast>) -> bool {
+    let driver_cx = unsafe { as_driver_cx(data) };
+    if !limit::check_diag_limit(driver_cx.lint_level_at(diag.lint, diag.node)) {
+        return false;
+    }
+    if output::is_json_output() {
+        output::print_json_diag(driver_cx, diag);
+    }
+    driver_cx.emit_diag(diag)
 }
 
 // False positive because `ItemKind` is non-exhaustive
@@ -69,20 +97,102 @@ extern "C" fn item<'ast>(data: &'ast (), id: ItemId) -> FfiOption<ItemKind<'ast>
     unsafe { as_driver_cx(data) }.item(id).into()
 }
 
-extern "C" fn body<'ast>(data: &'ast (), id: BodyId) -> &'ast Body<'ast> {
-    unsafe { as_driver_cx(data) }.body(id)
+extern "C" fn parent_item(data: &(), id: ItemId) -> FfiOption<ItemId> {
+    unsafe { as_driver_cx(data) }.parent_item(id).into()
+}
+
+extern "C" fn macro_info<'ast>(data: &'ast (), src_id: SpanSrcId) -> ffi::FfiOption<&'ast MacroInfo<'ast>> {
+    unsafe { as_driver_cx(data) }.macro_info(src_id).into()
+}
+
+extern "C" fn all_items<'ast>(data: &'ast ()) -> ffi::FfiSlice<'ast, ItemId> {
+    unsafe { as_driver_cx(data) }.all_items().into()
+}
+
+// False positive because `VisibilityKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn item_visibility(data: &(), id: ItemId) -> VisibilityKind {
+    unsafe { as_driver_cx(data) }.item_visibility(id)
+}
+
+extern "C" fn definition_span(data: &(), id: ItemId) -> FfiOption<SpanId> {
+    unsafe { as_driver_cx(data) }.definition_span(id).into()
+}
+
+extern "C" fn resolve_item_path<'ast>(data: &'ast (), id: ItemId) -> FfiOption<ffi::FfiStr<'ast>> {
+    unsafe { as_driver_cx(data) }
+        .resolve_item_path(id)
+        .map(Into::into)
+        .into()
+}
+
+extern "C" fn body<'ast>(data: &'ast (), id: BodyId) -> ffi::FfiResult<&'ast Body<'ast>, ffi::FfiStr<'ast>> {
+    unsafe { as_driver_cx(data) }.body(id).map_err(Into::into).into()
+}
+
+// False positive because `ExprKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn expr<'ast>(data: &'ast (), id: ExprId) -> FfiOption<ExprKind<'ast>> {
+    unsafe { as_driver_cx(data) }.expr(id).into()
+}
+
+extern "C" fn callee_of(data: &(), id: ExprId) -> FfiOption<ItemId> {
+    unsafe { as_driver_cx(data) }.callee_of(id).into()
+}
+
+extern "C" fn resolve_const_value<'ast>(data: &'ast (), id: ExprId) -> FfiOption<&'ast ConstValue<'ast>> {
+    unsafe { as_driver_cx(data) }.resolve_const_value(id).into()
+}
+
+extern "C" fn item_deprecation<'ast>(data: &'ast (), id: ItemId) -> FfiOption<ItemDeprecation<'ast>> {
+    unsafe { as_driver_cx(data) }.item_deprecation(id).into()
+}
+
+extern "C" fn item_attrs<'ast>(data: &'ast (), id: ItemId) -> ffi::FfiSlice<'ast, Attr<'ast>> {
+    unsafe { as_driver_cx(data) }.item_attrs(id).into()
 }
 
 extern "C" fn resolve_ty_ids<'ast>(data: &'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId> {
     unsafe { as_driver_cx(data) }.resolve_ty_ids((&path).into()).into()
 }
 
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn type_implements_trait<'ast>(
+    data: &'ast (),
+    ty: SemTyKind<'ast>,
+    trait_id: TyDefId,
+    args: ffi::FfiSlice<'_, SemTyKind<'ast>>,
+) -> bool {
+    unsafe { as_driver_cx(data) }.type_implements_trait(ty, trait_id, args.get())
+}
+
+extern "C" fn trait_impls<'ast>(data: &'ast (), trait_id: TyDefId) -> ffi::FfiSlice<'ast, ItemId> {
+    unsafe { as_driver_cx(data) }.trait_impls(trait_id).into()
+}
+
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn check_trait_impl<'ast>(data: &'ast (), ty: SemTyKind<'ast>, known_trait: KnownTrait) -> bool {
+    unsafe { as_driver_cx(data) }.check_trait_impl(ty, known_trait)
+}
+
 // False positive because `SemTyKind` is non-exhaustive
 #[allow(improper_ctypes_definitions)]
 extern "C" fn expr_ty<'ast>(data: &'ast (), expr: ExprId) -> SemTyKind<'ast> {
     unsafe { as_driver_cx(data) }.expr_ty(expr)
 }
 
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn field_ty_of<'ast>(
+    data: &'ast (),
+    adt: &'ast SemAdtTy<'ast>,
+    name: ffi::FfiStr<'_>,
+) -> ffi::FfiOption<SemTyKind<'ast>> {
+    unsafe { as_driver_cx(data) }.field_ty_of(adt, name.get()).into()
+}
+
 extern "C" fn span<'ast>(data: &'ast (), span_id: SpanId) -> &'ast Span<'ast> {
     unsafe { as_driver_cx(data) }.span(span_id)
 }
@@ -91,12 +201,28 @@ extern "C" fn span_snippet<'ast>(data: &'ast (), span: &Span<'ast>) -> ffi::FfiO
     unsafe { as_driver_cx(data) }.span_snippet(span).map(Into::into).into()
 }
 
-extern "C" fn symbol_str<'ast>(data: &'ast (), sym: SymbolId) -> ffi::FfiStr<'ast> {
-    unsafe { as_driver_cx(data) }.symbol_str(sym).into()
+extern "C" fn span_macro_origin<'ast>(data: &'ast (), span: &Span<'ast>) -> ffi::FfiOption<MacroOrigin> {
+    unsafe { as_driver_cx(data) }.span_macro_origin(span).into()
 }
 
-extern "C" fn resolve_method_target(data: &(), id: ExprId) -> ItemId {
-    unsafe { as_driver_cx(data) }.resolve_method_target(id)
+extern "C" fn span_pos_to_file_pos<'ast>(
+    data: &'ast (),
+    file: &SpanSource<'ast>,
+    pos: usize,
+) -> ffi::FfiOption<FilePos> {
+    unsafe { as_driver_cx(data) }.span_pos_to_file_pos(file, pos).into()
+}
+
+extern "C" fn span_pos_to_line_bounds<'ast>(
+    data: &'ast (),
+    file: &SpanSource<'ast>,
+    pos: usize,
+) -> ffi::FfiOption<LineBounds> {
+    unsafe { as_driver_cx(data) }.span_pos_to_line_bounds(file, pos).into()
+}
+
+extern "C" fn symbol_str<'ast>(data: &'ast (), sym: SymbolId) -> ffi::FfiStr<'ast> {
+    unsafe { as_driver_cx(data) }.symbol_str(sym).into()
 }
 
 /// # Safety
@@ -108,16 +234,35 @@ unsafe fn as_driver_cx<'ast>(data: &'ast ()) -> &'ast dyn DriverContext<'ast> {
 
 pub trait DriverContext<'ast> {
     fn lint_level_at(&'ast self, lint: &'static Lint, node: EmissionNode) -> Level;
-    fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>);
+    fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>) -> bool;
 
     fn item(&'ast self, api_id: ItemId) -> Option<ItemKind<'ast>>;
-    fn body(&'ast self, api_id: BodyId) -> &'ast Body<'ast>;
+    fn parent_item(&'ast self, api_id: ItemId) -> Option<ItemId>;
+    fn all_items(&'ast self) -> &'ast [ItemId];
+    fn macro_info(&'ast self, src_id: SpanSrcId) -> Option<&'ast MacroInfo<'ast>>;
+    fn item_visibility(&'ast self, api_id: ItemId) -> VisibilityKind;
+    fn definition_span(&'ast self, api_id: ItemId) -> Option<SpanId>;
+    fn resolve_item_path(&'ast self, api_id: ItemId) -> Option<&'ast str>;
+    /// # Errors
+    /// Returns an error message, if the given [`BodyId`] is unknown.
+    fn body(&'ast self, api_id: BodyId) -> Result<&'ast Body<'ast>, &'ast str>;
+    fn expr(&'ast self, api_id: ExprId) -> Option<ExprKind<'ast>>;
+    fn callee_of(&'ast self, api_id: ExprId) -> Option<ItemId>;
+    fn resolve_const_value(&'ast self, api_id: ExprId) -> Option<&'ast ConstValue<'ast>>;
 
+    fn item_deprecation(&'ast self, api_id: ItemId) -> Option<ItemDeprecation<'ast>>;
+    fn item_attrs(&'ast self, api_id: ItemId) -> &'ast [Attr<'ast>];
     fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId];
+    fn type_implements_trait(&'ast self, ty: SemTyKind<'ast>, trait_id: TyDefId, args: &[SemTyKind<'ast>]) -> bool;
+    fn trait_impls(&'ast self, trait_id: TyDefId) -> &'ast [ItemId];
+    fn check_trait_impl(&'ast self, ty: SemTyKind<'ast>, known_trait: KnownTrait) -> bool;
 
     fn expr_ty(&'ast self, expr: ExprId) -> SemTyKind<'ast>;
+    fn field_ty_of(&'ast self, adt: &'ast SemAdtTy<'ast>, name: &str) -> Option<SemTyKind<'ast>>;
     fn span(&'ast self, owner: SpanId) -> &'ast Span<'ast>;
     fn span_snippet(&'ast self, span: &Span<'ast>) -> Option<&'ast str>;
+    fn span_macro_origin(&'ast self, span: &Span<'ast>) -> Option<MacroOrigin>;
+    fn span_pos_to_file_pos(&'ast self, file: &SpanSource<'ast>, pos: usize) -> Option<FilePos>;
+    fn span_pos_to_line_bounds(&'ast self, file: &SpanSource<'ast>, pos: usize) -> Option<LineBounds>;
     fn symbol_str(&'ast self, api_id: SymbolId) -> &'ast str;
-    fn resolve_method_target(&'ast self, id: ExprId) -> ItemId;
 }
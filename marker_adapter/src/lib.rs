@@ -4,25 +4,36 @@
 #![allow(clippy::module_name_repetitions)]
 
 pub mod context;
+mod limit;
 mod loader;
+mod output;
+mod timings;
 pub use loader::LintCrateInfo;
 use loader::{LintCrateRegistry, LoadingError};
 
 use marker_api::{
     ast::{
         expr::ExprKind,
-        item::{Body, EnumVariant, Field, ItemKind},
+        generic::SynGenericParamKind,
+        item::{Body, EnumVariant, Field, FnKind, ItemKind},
+        pat::PatKind,
         stmt::StmtKind,
+        visitor::{self, Visitor},
         Crate,
     },
     context::AstContext,
     LintPass, LintPassInfo,
 };
-use marker_utils::visitor::{self, Visitor};
 use std::{cell::RefCell, ops::ControlFlow};
 use thiserror::Error;
 
 pub const LINT_CRATES_ENV: &str = "MARKER_LINT_CRATES";
+pub const TIMINGS_ENV: &str = "MARKER_TIMINGS";
+pub const OUTPUT_FORMAT_ENV: &str = "MARKER_OUTPUT_FORMAT";
+pub const MAX_DIAGNOSTICS_ENV: &str = "MARKER_MAX_DIAGNOSTICS";
+/// A value, which is unique for every run of Marker, that prints diagnostics to
+/// stdout. The driver tracks it, to prevent Cargo from answering from its cache.
+pub const RUN_ID_ENV: &str = "MARKER_RUN_ID";
 
 #[derive(Debug, Error)]
 pub enum AdapterError {
@@ -32,7 +43,7 @@ pub enum AdapterError {
     /// the `marker_adapter` crate.
     #[error("the content of the `{LINT_CRATES_ENV}` environment value is malformed")]
     LintCratesEnvMalformed,
-    #[error("error while loading the lint crate: {0:#?}")]
+    #[error("error while loading the lint crate: {0}")]
     LoadingError(#[from] LoadingError),
 }
 
@@ -62,7 +73,8 @@ impl Adapter {
     /// This function will return an error if an error occurs during the lint
     /// loading process.
     pub fn new(lint_crates: &[LintCrateInfo]) -> Result<Self, AdapterError> {
-        let external_lint_crates = LintCrateRegistry::new(lint_crates)?;
+        let timings = std::env::var_os(TIMINGS_ENV).is_some();
+        let external_lint_crates = LintCrateRegistry::new(lint_crates, timings)?;
         Ok(Self {
             inner: RefCell::new(AdapterInner { external_lint_crates }),
         })
@@ -76,42 +88,75 @@ impl Adapter {
     pub fn process_krate<'ast>(&self, cx: &'ast AstContext<'ast>, krate: &Crate<'ast>) {
         let inner = &mut *self.inner.borrow_mut();
 
-        inner.external_lint_crates.set_ast_context(cx);
+        let _cx_guard = inner.external_lint_crates.set_ast_context(cx);
 
         for item in krate.items() {
-            visitor::traverse_item::<()>(cx, inner, *item);
+            visitor::walk_item::<()>(cx, inner, *item);
+        }
+
+        limit::print_suppressed_summary();
+
+        let timings = inner.external_lint_crates.collect_timings();
+        if !timings.is_empty() {
+            // Timings are written to stderr, to not interfere with machine
+            // readable output on stdout.
+            eprint!("{}", timings::format_timings(timings));
         }
     }
 }
 
-impl Visitor<()> for AdapterInner {
-    fn visit_item<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) -> ControlFlow<()> {
+impl<'ast> Visitor<'ast, ()> for AdapterInner {
+    fn visit_item(&mut self, cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_item(cx, item);
+        if let ItemKind::Fn(fn_item) = item {
+            if let Some(body_id) = fn_item.body_id() {
+                self.external_lint_crates
+                    .check_fn(cx, FnKind::Fn(fn_item), cx.body(body_id));
+            }
+        }
         ControlFlow::Continue(())
     }
 
-    fn visit_field<'ast>(&mut self, cx: &'ast AstContext<'ast>, field: &'ast Field<'ast>) -> ControlFlow<()> {
+    fn visit_field(&mut self, cx: &'ast AstContext<'ast>, field: &'ast Field<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_field(cx, field);
         ControlFlow::Continue(())
     }
 
-    fn visit_variant<'ast>(&mut self, cx: &'ast AstContext<'ast>, variant: &'ast EnumVariant<'ast>) -> ControlFlow<()> {
+    fn visit_variant(&mut self, cx: &'ast AstContext<'ast>, variant: &'ast EnumVariant<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_variant(cx, variant);
         ControlFlow::Continue(())
     }
 
-    fn visit_body<'ast>(&mut self, cx: &'ast AstContext<'ast>, body: &'ast Body<'ast>) -> ControlFlow<()> {
+    fn visit_generic_param(
+        &mut self,
+        cx: &'ast AstContext<'ast>,
+        param: &'ast SynGenericParamKind<'ast>,
+    ) -> ControlFlow<()> {
+        self.external_lint_crates.check_generic_param(cx, param);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_body(&mut self, cx: &'ast AstContext<'ast>, body: &'ast Body<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_body(cx, body);
         ControlFlow::Continue(())
     }
 
-    fn visit_stmt<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: StmtKind<'ast>) -> ControlFlow<()> {
+    fn visit_stmt(&mut self, cx: &'ast AstContext<'ast>, stmt: StmtKind<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_stmt(cx, stmt);
         ControlFlow::Continue(())
     }
 
-    fn visit_expr<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> ControlFlow<()> {
+    fn visit_expr(&mut self, cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> ControlFlow<()> {
         self.external_lint_crates.check_expr(cx, expr);
+        if let ExprKind::Closure(closure) = expr {
+            let body = cx.body(closure.body_id());
+            self.external_lint_crates.check_fn(cx, FnKind::Closure(closure), body);
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_pat(&mut self, cx: &'ast AstContext<'ast>, pat: PatKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_pat(cx, pat);
         ControlFlow::Continue(())
     }
 }
@@ -0,0 +1,61 @@
+//! Limits the number of diagnostics reported for a crate.
+//!
+//! The limit is read from the [`MAX_DIAGNOSTICS_ENV`] environment value. A
+//! value of `0` or an unset value means that all diagnostics are reported.
+//!
+//! The counters are process-wide. Every crate is checked by a separate driver
+//! process, which means that the limit applies to each crate individually and
+//! not to the entire `cargo marker` run. Diagnostics of lints with the
+//! [`Level::Deny`] level or higher are neither suppressed nor counted.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    OnceLock,
+};
+
+use marker_api::lint::Level;
+
+use crate::MAX_DIAGNOSTICS_ENV;
+
+static EMITTED: AtomicUsize = AtomicUsize::new(0);
+static SUPPRESSED: AtomicUsize = AtomicUsize::new(0);
+
+fn max_diagnostics() -> Option<usize> {
+    static MAX: OnceLock<Option<usize>> = OnceLock::new();
+    *MAX.get_or_init(|| {
+        std::env::var(MAX_DIAGNOSTICS_ENV)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .filter(|max| *max != 0)
+    })
+}
+
+/// Counts the diagnostic and returns `true`, if it should be reported.
+///
+/// Diagnostics with the [`Level::Deny`] level or higher are always reported
+/// and don't count towards the limit, as suppressing them would change the
+/// result of the compilation.
+pub(crate) fn check_diag_limit(level: Level) -> bool {
+    let Some(max) = max_diagnostics() else {
+        return true;
+    };
+    if level >= Level::Deny {
+        return true;
+    }
+
+    if EMITTED.fetch_add(1, Ordering::Relaxed) < max {
+        true
+    } else {
+        SUPPRESSED.fetch_add(1, Ordering::Relaxed);
+        false
+    }
+}
+
+/// Prints a summary of the suppressed diagnostics to stderr, if the limit
+/// was reached.
+pub(crate) fn print_suppressed_summary() {
+    let suppressed = SUPPRESSED.load(Ordering::Relaxed);
+    if suppressed > 0 {
+        eprintln!("warning: {suppressed} more diagnostics suppressed; re-run with `--max-diagnostics=0` to see all");
+    }
+}
@@ -1,10 +1,11 @@
 use libloading::Library;
 use marker_api::{interface::LintCrateBindings, AstContext};
-use marker_api::{LintPass, LintPassInfo, MARKER_API_VERSION};
-use std::path::PathBuf;
+use marker_api::{LintPass, LintPassInfo, MARKER_API_VERSION, MARKER_RUSTC_VERSION};
+use std::{marker::PhantomData, path::PathBuf};
 use thiserror::Error;
 
 use super::{AdapterError, LINT_CRATES_ENV};
+use crate::timings::{PassTimings, TimingLintPass};
 
 /// A struct describing a lint crate that can be loaded
 #[derive(Debug, Clone)]
@@ -45,28 +46,46 @@ impl LintCrateInfo {
 /// to call the respective methods on all of them.
 #[derive(Debug, Default)]
 pub struct LintCrateRegistry {
-    passes: Vec<LoadedLintCrate>,
+    passes: Vec<TimingLintPass<LoadedLintCrate>>,
 }
 
 impl LintCrateRegistry {
-    pub fn new(lint_crates: &[LintCrateInfo]) -> Result<Self, LoadingError> {
+    /// Loads the given lint crates. If `timings` is `true`, the time spent in
+    /// each lint crate will be recorded.
+    pub fn new(lint_crates: &[LintCrateInfo], timings: bool) -> Result<Self, LoadingError> {
         let mut new_self = Self::default();
 
         for krate in lint_crates {
-            new_self.passes.push(LoadedLintCrate::try_from_info(krate.clone())?);
+            let pass = LoadedLintCrate::try_from_info(krate.clone())?;
+            new_self
+                .passes
+                .push(TimingLintPass::new(pass, krate.name.clone(), timings));
         }
 
         Ok(new_self)
     }
 
-    pub(super) fn set_ast_context<'ast>(&self, cx: &'ast AstContext<'ast>) {
+    /// Sets the [`AstContext`] for all lint crates. The context is unset again,
+    /// once the returned guard is dropped.
+    pub(super) fn set_ast_context<'ast>(&self, cx: &'ast AstContext<'ast>) -> AstContextGuard<'ast> {
+        let mut clear_fns = Vec::with_capacity(self.passes.len());
         for lint_pass in &self.passes {
-            (lint_pass.bindings.set_ast_context)(cx);
+            let bindings = &lint_pass.inner().bindings;
+            (bindings.set_ast_context)(cx);
+            clear_fns.push(bindings.clear_ast_context);
+        }
+        AstContextGuard {
+            clear_fns,
+            _lifetime: PhantomData,
         }
     }
 
     pub(crate) fn collect_lint_pass_info(&self) -> Vec<LintPassInfo> {
-        self.passes.iter().map(|pass| (pass.bindings.info)()).collect()
+        self.passes.iter().map(LintPass::info).collect()
+    }
+
+    pub(crate) fn collect_timings(&self) -> Vec<&PassTimings> {
+        self.passes.iter().filter_map(TimingLintPass::timings).collect()
     }
 }
 
@@ -77,14 +96,14 @@ impl LintPass for LintCrateRegistry {
     }
 
     fn check_item<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: marker_api::ast::item::ItemKind<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_item)(cx, item);
+        for lp in &mut self.passes {
+            lp.check_item(cx, item);
         }
     }
 
     fn check_field<'ast>(&mut self, cx: &'ast AstContext<'ast>, field: &'ast marker_api::ast::item::Field<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_field)(cx, field);
+        for lp in &mut self.passes {
+            lp.check_field(cx, field);
         }
     }
 
@@ -93,26 +112,53 @@ impl LintPass for LintCrateRegistry {
         cx: &'ast AstContext<'ast>,
         variant: &'ast marker_api::ast::item::EnumVariant<'ast>,
     ) {
-        for lp in &self.passes {
-            (lp.bindings.check_variant)(cx, variant);
+        for lp in &mut self.passes {
+            lp.check_variant(cx, variant);
+        }
+    }
+
+    fn check_generic_param<'ast>(
+        &mut self,
+        cx: &'ast AstContext<'ast>,
+        param: &'ast marker_api::ast::generic::SynGenericParamKind<'ast>,
+    ) {
+        for lp in &mut self.passes {
+            lp.check_generic_param(cx, param);
         }
     }
 
     fn check_body<'ast>(&mut self, cx: &'ast AstContext<'ast>, body: &'ast marker_api::ast::item::Body<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_body)(cx, body);
+        for lp in &mut self.passes {
+            lp.check_body(cx, body);
         }
     }
 
     fn check_stmt<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: marker_api::ast::stmt::StmtKind<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_stmt)(cx, stmt);
+        for lp in &mut self.passes {
+            lp.check_stmt(cx, stmt);
         }
     }
 
     fn check_expr<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: marker_api::ast::expr::ExprKind<'ast>) {
-        for lp in &self.passes {
-            (lp.bindings.check_expr)(cx, expr);
+        for lp in &mut self.passes {
+            lp.check_expr(cx, expr);
+        }
+    }
+
+    fn check_pat<'ast>(&mut self, cx: &'ast AstContext<'ast>, pat: marker_api::ast::pat::PatKind<'ast>) {
+        for lp in &mut self.passes {
+            lp.check_pat(cx, pat);
+        }
+    }
+
+    fn check_fn<'ast>(
+        &mut self,
+        cx: &'ast AstContext<'ast>,
+        fn_kind: marker_api::ast::item::FnKind<'ast>,
+        body: &'ast marker_api::ast::item::Body<'ast>,
+    ) {
+        for lp in &mut self.passes {
+            lp.check_fn(cx, fn_kind, body);
         }
     }
 }
@@ -130,6 +176,78 @@ impl std::fmt::Debug for LoadedLintCrate {
     }
 }
 
+#[warn(clippy::missing_trait_methods)]
+impl LintPass for LoadedLintCrate {
+    fn info(&self) -> LintPassInfo {
+        (self.bindings.info)()
+    }
+
+    fn check_item<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: marker_api::ast::item::ItemKind<'ast>) {
+        (self.bindings.check_item)(cx, item);
+    }
+
+    fn check_field<'ast>(&mut self, cx: &'ast AstContext<'ast>, field: &'ast marker_api::ast::item::Field<'ast>) {
+        (self.bindings.check_field)(cx, field);
+    }
+
+    fn check_variant<'ast>(
+        &mut self,
+        cx: &'ast AstContext<'ast>,
+        variant: &'ast marker_api::ast::item::EnumVariant<'ast>,
+    ) {
+        (self.bindings.check_variant)(cx, variant);
+    }
+
+    fn check_generic_param<'ast>(
+        &mut self,
+        cx: &'ast AstContext<'ast>,
+        param: &'ast marker_api::ast::generic::SynGenericParamKind<'ast>,
+    ) {
+        (self.bindings.check_generic_param)(cx, param);
+    }
+
+    fn check_body<'ast>(&mut self, cx: &'ast AstContext<'ast>, body: &'ast marker_api::ast::item::Body<'ast>) {
+        (self.bindings.check_body)(cx, body);
+    }
+
+    fn check_stmt<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: marker_api::ast::stmt::StmtKind<'ast>) {
+        (self.bindings.check_stmt)(cx, stmt);
+    }
+
+    fn check_expr<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: marker_api::ast::expr::ExprKind<'ast>) {
+        (self.bindings.check_expr)(cx, expr);
+    }
+
+    fn check_pat<'ast>(&mut self, cx: &'ast AstContext<'ast>, pat: marker_api::ast::pat::PatKind<'ast>) {
+        (self.bindings.check_pat)(cx, pat);
+    }
+
+    fn check_fn<'ast>(
+        &mut self,
+        cx: &'ast AstContext<'ast>,
+        fn_kind: marker_api::ast::item::FnKind<'ast>,
+        body: &'ast marker_api::ast::item::Body<'ast>,
+    ) {
+        (self.bindings.check_fn)(cx, fn_kind, body);
+    }
+}
+
+/// Unsets the [`AstContext`] of all lint crates when it's dropped. See
+/// [`LintCrateRegistry::set_ast_context`].
+#[must_use = "the context is unset, once the guard is dropped"]
+pub(crate) struct AstContextGuard<'ast> {
+    clear_fns: Vec<extern "C" fn()>,
+    _lifetime: PhantomData<&'ast AstContext<'ast>>,
+}
+
+impl Drop for AstContextGuard<'_> {
+    fn drop(&mut self) {
+        for clear_fn in &self.clear_fns {
+            clear_fn();
+        }
+    }
+}
+
 impl LoadedLintCrate {
     fn try_from_info(info: LintCrateInfo) -> Result<Self, LoadingError> {
         let lib: &'static Library = Box::leak(Box::new(unsafe { Library::new(&info.path) }?));
@@ -154,6 +272,24 @@ impl LoadedLintCrate {
             });
         }
 
+        // Check the rustc version, since some types passed to the lint crate
+        // rely on Rust's layout. Lint crates without this symbol have been
+        // compiled against an older `marker_api` version.
+        let get_rustc_version = {
+            unsafe {
+                lib.get::<unsafe extern "C" fn() -> &'static str>(b"marker_rustc_version\0")
+                    .map_err(|_| LoadingError::IncompatibleVersion {
+                        krate_version: krate_api_version.to_string(),
+                    })?
+            }
+        };
+        let krate_rustc_version = unsafe { get_rustc_version() };
+        if krate_rustc_version != MARKER_RUSTC_VERSION {
+            return Err(LoadingError::IncompatibleRustcVersion {
+                krate_version: krate_rustc_version.to_string(),
+            });
+        }
+
         // Load bindings
         let get_lint_crate_bindings = unsafe {
             lib.get::<extern "C" fn() -> LintCrateBindings>(b"marker_lint_crate_bindings\0")
@@ -177,6 +313,15 @@ pub enum LoadingError {
     MissingApiSymbol,
     #[error("the loaded crate doesn't contain the `marker_lint_crate_bindings` symbol")]
     MissingBindingSymbol,
-    #[error("incompatible api version:\n- lint-crate api: {krate_version}\n- driver api: {MARKER_API_VERSION}")]
+    #[error(
+        "incompatible api version:\n- lint-crate api: {krate_version}\n- driver api: {MARKER_API_VERSION}\n\n\
+        update the `marker_api` dependency of the lint crate to `{MARKER_API_VERSION}`, \
+        or install the matching driver with `cargo marker setup`"
+    )]
     IncompatibleVersion { krate_version: String },
+    #[error(
+        "incompatible rustc version:\n- lint-crate rustc: {krate_version}\n- driver rustc: {MARKER_RUSTC_VERSION}\n\n\
+        lint crates have to be compiled with the toolchain of the driver, rebuild them with `cargo marker`"
+    )]
+    IncompatibleRustcVersion { krate_version: String },
 }
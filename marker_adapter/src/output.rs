@@ -0,0 +1,159 @@
+//! Machine readable output for diagnostics emitted by lint crates.
+//!
+//! If the [`OUTPUT_FORMAT_ENV`] environment value is set to `json`, every
+//! diagnostic is printed to stdout as a single line JSON object, before it's
+//! forwarded to the driver.
+
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use marker_api::{
+    ast::Span,
+    diagnostic::{Applicability, Diagnostic, DiagnosticPart},
+    lint::Level,
+};
+use serde::Serialize;
+
+use crate::{context::DriverContext, OUTPUT_FORMAT_ENV};
+
+/// Returns `true`, if diagnostics should be printed as JSON.
+pub(crate) fn is_json_output() -> bool {
+    static IS_JSON: OnceLock<bool> = OnceLock::new();
+    *IS_JSON.get_or_init(|| std::env::var(OUTPUT_FORMAT_ENV).map_or(false, |format| format == "json"))
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    lint_name: String,
+    level: &'static str,
+    message: &'a str,
+    file: Option<&'a str>,
+    #[serde(flatten)]
+    pos: JsonSpanPos,
+    suggestions: Vec<JsonSuggestion<'a>>,
+}
+
+#[derive(Serialize, Default)]
+struct JsonSpanPos {
+    line: Option<u32>,
+    col: Option<u32>,
+    end_line: Option<u32>,
+    end_col: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct JsonSuggestion<'a> {
+    message: &'a str,
+    applicability: String,
+    parts: Vec<JsonSuggestionPart<'a>>,
+}
+
+/// The `file`, `byte_start` and `byte_end` fields are only set for spans
+/// originating from a file. The file path is absolute, to allow tools to
+/// apply the replacement.
+#[derive(Serialize)]
+struct JsonSuggestionPart<'a> {
+    file: Option<PathBuf>,
+    byte_start: Option<usize>,
+    byte_end: Option<usize>,
+    #[serde(flatten)]
+    pos: JsonSpanPos,
+    replacement: &'a str,
+}
+
+impl<'a> JsonSuggestionPart<'a> {
+    fn new<'ast>(driver_cx: &'ast dyn DriverContext<'ast>, span: &Span<'ast>, replacement: &'a str) -> Self {
+        let file = span.file_name().map(absolute_path);
+        let is_file = file.is_some();
+        Self {
+            file,
+            byte_start: is_file.then(|| span.start()),
+            byte_end: is_file.then(|| span.end()),
+            pos: span_pos(driver_cx, span),
+            replacement,
+        }
+    }
+}
+
+/// Prints the given diagnostic as a single line JSON object to stdout.
+pub(crate) fn print_json_diag<'ast>(driver_cx: &'ast dyn DriverContext<'ast>, diag: &Diagnostic<'_, 'ast>) {
+    let suggestions = diag
+        .parts
+        .get()
+        .iter()
+        .filter_map(|part| match part {
+            DiagnosticPart::Suggestion { msg, span, sugg, app } => Some(JsonSuggestion {
+                message: msg.get(),
+                applicability: applicability_str(*app),
+                parts: vec![JsonSuggestionPart::new(driver_cx, span, sugg.get())],
+            }),
+            DiagnosticPart::MultipartSuggestion { msg, parts, app } => Some(JsonSuggestion {
+                message: msg.get(),
+                applicability: applicability_str(*app),
+                parts: parts
+                    .get()
+                    .iter()
+                    .map(|part| JsonSuggestionPart::new(driver_cx, part.span, part.sugg.get()))
+                    .collect(),
+            }),
+            _ => None,
+        })
+        .collect();
+
+    let json_diag = JsonDiagnostic {
+        lint_name: diag.lint.name.to_ascii_lowercase(),
+        level: level_str(driver_cx.lint_level_at(diag.lint, diag.node)),
+        message: diag.msg(),
+        file: diag.span.file_name(),
+        pos: span_pos(driver_cx, diag.span),
+        suggestions,
+    };
+
+    let line = serde_json::to_string(&json_diag).expect("the diagnostic can always be serialized");
+    let mut stdout = std::io::stdout().lock();
+    // Errors are ignored, a closed stdout shouldn't stop the linting process
+    let _ = writeln!(stdout, "{line}");
+}
+
+fn span_pos<'ast>(driver_cx: &'ast dyn DriverContext<'ast>, span: &Span<'ast>) -> JsonSpanPos {
+    let start = driver_cx.span_pos_to_file_pos(span.source(), span.start());
+    let end = driver_cx.span_pos_to_file_pos(span.source(), span.end());
+    JsonSpanPos {
+        line: start.map(|pos| pos.line),
+        col: start.map(|pos| pos.col),
+        end_line: end.map(|pos| pos.line),
+        end_col: end.map(|pos| pos.col),
+    }
+}
+
+/// File names of spans are relative to the root of the compiled crate, if
+/// the file is located inside the crate directory. Otherwise, they're
+/// relative to the current directory or already absolute.
+fn absolute_path(file_name: &str) -> PathBuf {
+    let path = Path::new(file_name);
+    if let Some(crate_root) = std::env::var_os("CARGO_MANIFEST_DIR") {
+        let crate_path = Path::new(&crate_root).join(path);
+        if crate_path.exists() {
+            return crate_path;
+        }
+    }
+    std::env::current_dir().map_or_else(|_| path.to_path_buf(), |dir| dir.join(path))
+}
+
+fn level_str(level: Level) -> &'static str {
+    match level {
+        Level::Allow => "allow",
+        Level::Warn => "warn",
+        Level::ForceWarn => "force-warn",
+        Level::Deny => "deny",
+        Level::Forbid => "forbid",
+        _ => "unknown",
+    }
+}
+
+fn applicability_str(app: Applicability) -> String {
+    format!("{app:?}")
+}
@@ -0,0 +1,198 @@
+//! This module contains the [`TimingLintPass`] used to profile lint passes.
+
+use std::time::{Duration, Instant};
+
+use marker_api::{
+    ast::{
+        expr::ExprKind,
+        generic::SynGenericParamKind,
+        item::{Body, EnumVariant, Field, FnKind, ItemKind},
+        pat::PatKind,
+        stmt::StmtKind,
+    },
+    AstContext, LintPass, LintPassInfo,
+};
+
+/// The `check_*` callbacks of a [`LintPass`] which are tracked by the
+/// [`TimingLintPass`]. The discriminant is used as an index into the
+/// recorded measurements.
+#[derive(Debug, Clone, Copy)]
+enum Callback {
+    Item,
+    Field,
+    Variant,
+    GenericParam,
+    Body,
+    Stmt,
+    Expr,
+    Pat,
+    Fn,
+}
+
+impl Callback {
+    const ALL: [Callback; 9] = [
+        Callback::Item,
+        Callback::Field,
+        Callback::Variant,
+        Callback::GenericParam,
+        Callback::Body,
+        Callback::Stmt,
+        Callback::Expr,
+        Callback::Pat,
+        Callback::Fn,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Callback::Item => "check_item",
+            Callback::Field => "check_field",
+            Callback::Variant => "check_variant",
+            Callback::GenericParam => "check_generic_param",
+            Callback::Body => "check_body",
+            Callback::Stmt => "check_stmt",
+            Callback::Expr => "check_expr",
+            Callback::Pat => "check_pat",
+            Callback::Fn => "check_fn",
+        }
+    }
+}
+
+/// The accumulated measurements of a single callback.
+#[derive(Debug, Default, Clone, Copy)]
+struct CallbackTiming {
+    calls: u64,
+    total: Duration,
+}
+
+/// The measurements of one [`TimingLintPass`].
+#[derive(Debug, Clone)]
+pub struct PassTimings {
+    name: String,
+    callbacks: [CallbackTiming; Callback::ALL.len()],
+}
+
+impl PassTimings {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            callbacks: Default::default(),
+        }
+    }
+
+    /// The total time spent inside the callbacks of this pass.
+    pub fn total(&self) -> Duration {
+        self.callbacks.iter().map(|timing| timing.total).sum()
+    }
+}
+
+/// This decorator wraps a [`LintPass`] and measures the time spent in each
+/// `check_*` callback, if timings have been enabled. Otherwise, the calls are
+/// simply forwarded to the wrapped pass.
+#[derive(Debug)]
+pub struct TimingLintPass<L> {
+    inner: L,
+    timings: Option<PassTimings>,
+}
+
+impl<L: LintPass> TimingLintPass<L> {
+    pub fn new(inner: L, name: String, enabled: bool) -> Self {
+        Self {
+            inner,
+            timings: enabled.then(|| PassTimings::new(name)),
+        }
+    }
+
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// Returns the recorded measurements or `None`, if timings are disabled.
+    pub fn timings(&self) -> Option<&PassTimings> {
+        self.timings.as_ref()
+    }
+
+    fn timed(&mut self, callback: Callback, f: impl FnOnce(&mut L)) {
+        if let Some(timings) = &mut self.timings {
+            let start = Instant::now();
+            f(&mut self.inner);
+            let timing = &mut timings.callbacks[callback as usize];
+            timing.calls += 1;
+            timing.total += start.elapsed();
+        } else {
+            f(&mut self.inner);
+        }
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<L: LintPass> LintPass for TimingLintPass<L> {
+    fn info(&self) -> LintPassInfo {
+        self.inner.info()
+    }
+
+    fn check_item<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+        self.timed(Callback::Item, |pass| pass.check_item(cx, item));
+    }
+
+    fn check_field<'ast>(&mut self, cx: &'ast AstContext<'ast>, field: &'ast Field<'ast>) {
+        self.timed(Callback::Field, |pass| pass.check_field(cx, field));
+    }
+
+    fn check_variant<'ast>(&mut self, cx: &'ast AstContext<'ast>, variant: &'ast EnumVariant<'ast>) {
+        self.timed(Callback::Variant, |pass| pass.check_variant(cx, variant));
+    }
+
+    fn check_generic_param<'ast>(&mut self, cx: &'ast AstContext<'ast>, param: &'ast SynGenericParamKind<'ast>) {
+        self.timed(Callback::GenericParam, |pass| pass.check_generic_param(cx, param));
+    }
+
+    fn check_body<'ast>(&mut self, cx: &'ast AstContext<'ast>, body: &'ast Body<'ast>) {
+        self.timed(Callback::Body, |pass| pass.check_body(cx, body));
+    }
+
+    fn check_stmt<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: StmtKind<'ast>) {
+        self.timed(Callback::Stmt, |pass| pass.check_stmt(cx, stmt));
+    }
+
+    fn check_expr<'ast>(&mut self, cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) {
+        self.timed(Callback::Expr, |pass| pass.check_expr(cx, expr));
+    }
+
+    fn check_pat<'ast>(&mut self, cx: &'ast AstContext<'ast>, pat: PatKind<'ast>) {
+        self.timed(Callback::Pat, |pass| pass.check_pat(cx, pat));
+    }
+
+    fn check_fn<'ast>(&mut self, cx: &'ast AstContext<'ast>, fn_kind: FnKind<'ast>, body: &'ast Body<'ast>) {
+        self.timed(Callback::Fn, |pass| pass.check_fn(cx, fn_kind, body));
+    }
+}
+
+/// Formats the given measurements as a summary table. The passes are sorted
+/// by their total time, with the slowest pass first.
+pub fn format_timings<'a>(timings: impl IntoIterator<Item = &'a PassTimings>) -> String {
+    use std::fmt::Write;
+
+    let mut timings: Vec<_> = timings.into_iter().collect();
+    timings.sort_by_key(|timing| std::cmp::Reverse(timing.total()));
+
+    let mut out = String::new();
+    let _ = writeln!(out, "Marker lint pass timings:");
+    let _ = writeln!(out, "{:<32} {:>12} {:>12}", "pass / callback", "calls", "time");
+    for pass in timings {
+        let calls: u64 = pass.callbacks.iter().map(|timing| timing.calls).sum();
+        let _ = writeln!(out, "{:<32} {:>12} {:>12.3?}", pass.name, calls, pass.total());
+        for callback in Callback::ALL {
+            let timing = pass.callbacks[callback as usize];
+            if timing.calls != 0 {
+                let _ = writeln!(
+                    out,
+                    "  {:<30} {:>12} {:>12.3?}",
+                    callback.name(),
+                    timing.calls,
+                    timing.total
+                );
+            }
+        }
+    }
+    out
+}
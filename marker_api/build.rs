@@ -0,0 +1,17 @@
+use std::{env, process::Command};
+
+fn main() {
+    // Lint crates and the driver have to be compiled by the same rustc version,
+    // since the types passed over the FFI boundary use Rust's layout in some
+    // places. The version is exported, to allow the adapter to verify this.
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version = Command::new(rustc)
+        .arg("-V")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or_else(|| "unknown".to_string(), |version| version.trim().to_string());
+
+    println!("cargo:rustc-env=MARKER_RUSTC_VERSION={version}");
+    println!("cargo:rerun-if-env-changed=RUSTC");
+}
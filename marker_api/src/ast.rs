@@ -11,6 +11,7 @@ pub mod item;
 pub mod pat;
 pub mod stmt;
 pub mod ty;
+pub mod visitor;
 
 #[derive(Debug)]
 pub struct Crate<'ast> {
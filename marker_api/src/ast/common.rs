@@ -6,8 +6,10 @@ mod callable;
 pub use callable::*;
 mod ast_path;
 pub use ast_path::*;
-
-use std::{fmt::Debug, marker::PhantomData};
+mod ty_path;
+pub use ty_path::*;
+mod attr;
+pub use attr::*;
 
 use super::generic::SynGenericArgs;
 
@@ -26,7 +28,29 @@ pub enum Abi {
     /// implementations. In general this means that the user has not selected a
     /// specific ABI.
     Default,
+    /// The C ABI of the target platform, like `extern "C"`.
     C,
+    /// The C ABI, which allows unwinding across the function boundary, like
+    /// `extern "C-unwind"`.
+    CUnwind,
+    /// The default ABI for system calls of the target platform, like `extern "system"`.
+    /// This is the same as [`Abi::C`] on most platforms.
+    System,
+    /// The system ABI, which allows unwinding across the function boundary, like
+    /// `extern "system-unwind"`.
+    SystemUnwind,
+    /// The x86 `cdecl` calling convention, like `extern "cdecl"`.
+    Cdecl,
+    /// The x86 `stdcall` calling convention, like `extern "stdcall"`.
+    Stdcall,
+    /// The x86 `fastcall` calling convention, like `extern "fastcall"`.
+    Fastcall,
+    /// The ARM calling convention, like `extern "aapcs"`.
+    Aapcs,
+    /// The x86_64 Windows calling convention, like `extern "win64"`.
+    Win64,
+    /// The x86_64 System V calling convention, like `extern "sysv64"`.
+    SysV64,
     /// FIXME: Remove this variant. See
     /// <https://doc.rust-lang.org/nightly/nightly-rustc/rustc_target/spec/abi/enum.Abi.html>
     Other,
@@ -123,26 +147,51 @@ impl Syncness {
     }
 }
 
-/// The semantic equivalent of a [`ConstExpr`][super::expr::ConstExpr], at least
-/// theoretically. This part of the API is sadly not done yet, so this is just a
-/// placeholder.
+/// The evaluated value of a constant, like a [`ConstExpr`][super::expr::ConstExpr],
+/// a const generic argument or the length of an array.
 ///
-/// See: rust-marker/marker#179
+/// Values are compared structurally. Floats are considered equal, if they have
+/// the same bit pattern. This means that `NaN` is equal to itself.
 #[repr(C)]
-pub struct ConstValue<'ast> {
-    _lifetime: PhantomData<&'ast ()>,
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum ConstValue<'ast> {
+    /// An integer value. Negative values of signed integers are sign extended
+    /// to 128 bits. `-1i32` is therefore represented as `u128::MAX`.
+    Integer(u128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    Str(&'ast str),
+    /// A value which can't be represented by the other variants, like the
+    /// value of a struct.
+    Opaque,
 }
 
-impl<'ast> Debug for ConstValue<'ast> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ConstValue {{ /* WIP: See rust-marker/marker#179 */}}")
-            .finish()
+impl<'ast> ConstValue<'ast> {
+    /// Returns the value as an [`u64`], if it's an [`Integer`](Self::Integer)
+    /// which fits into an [`u64`]. This can be used to get the length of an
+    /// array.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Integer(value) => u64::try_from(*value).ok(),
+            _ => None,
+        }
     }
 }
 
-#[cfg(feature = "driver-api")]
-impl<'ast> ConstValue<'ast> {
-    pub fn new() -> Self {
-        Self { _lifetime: PhantomData }
+impl<'ast> PartialEq for ConstValue<'ast> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => a == b,
+            (Self::Float(a), Self::Float(b)) => a.to_bits() == b.to_bits(),
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Char(a), Self::Char(b)) => a == b,
+            (Self::Str(a), Self::Str(b)) => a == b,
+            (Self::Opaque, Self::Opaque) => true,
+            _ => false,
+        }
     }
 }
+
+impl<'ast> Eq for ConstValue<'ast> {}
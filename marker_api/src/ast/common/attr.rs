@@ -0,0 +1,73 @@
+use crate::{
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice, FfiStr},
+};
+
+use super::{Ident, Span, SpanId};
+
+/// An outer attribute attached to an item, like `#[must_use]` or
+/// `#[rustfmt::skip]`. Doc comments are represented as `doc` attributes, with
+/// the comment as their value.
+///
+/// ```
+/// #[must_use]
+/// //^^^^^^^ An attribute with the path `must_use`
+/// #[doc = "Some documentation"]
+/// //^^^   ^^^^^^^^^^^^^^^^^^^^ The value of the attribute
+/// //|
+/// // The path of the attribute
+/// pub fn foo() -> u32 {
+///     1
+/// }
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+pub struct Attr<'ast> {
+    path: FfiSlice<'ast, Ident<'ast>>,
+    value: FfiOption<FfiStr<'ast>>,
+    is_doc_comment: bool,
+    span: SpanId,
+}
+
+impl<'ast> Attr<'ast> {
+    /// The segments of the attribute path. For `#[rustfmt::skip]` this would
+    /// be `rustfmt` and `skip`.
+    pub fn path(&self) -> &[Ident<'ast>] {
+        self.path.get()
+    }
+
+    /// Returns `true`, if the path of this attribute matches the given path.
+    /// The segments of the given path are separated by `::`, like `rustfmt::skip`.
+    pub fn is_path(&self, path: &str) -> bool {
+        let mut segments = path.split("::");
+        self.path().iter().all(|ident| segments.next() == Some(ident.name())) && segments.next().is_none()
+    }
+
+    /// The value of the attribute, if it has the form `#[path = "value"]`. For
+    /// doc comments, this returns the content of the comment.
+    pub fn value_str(&self) -> Option<&str> {
+        self.value.get().map(FfiStr::get)
+    }
+
+    /// Returns `true`, if this attribute was written as a doc comment, like
+    /// `/// Some documentation`.
+    pub fn is_doc_comment(&self) -> bool {
+        self.is_doc_comment
+    }
+
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Attr<'ast> {
+    pub fn new(path: &'ast [Ident<'ast>], value: Option<FfiStr<'ast>>, is_doc_comment: bool, span: SpanId) -> Self {
+        Self {
+            path: path.into(),
+            value: value.into(),
+            is_doc_comment,
+            span,
+        }
+    }
+}
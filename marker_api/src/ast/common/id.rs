@@ -109,12 +109,27 @@ new_id! {
 }
 
 new_id! {
-    /// **Unstable**
+    /// This id identifies the source of a [`Span`](super::Span), that doesn't
+    /// originate directly from a file. This can for example be a specific macro
+    /// expansion or desugaring.
     ///
-    /// This id is used to identify the source of a `Span`. This type is only intended for internal
-    /// use. For now it's only intended for drivers to map spans back
-    #[cfg_attr(feature = "driver-api", visibility::make(pub))]
-    pub(crate) SpanSrcId: u32
+    /// Every macro expansion has its own [`SpanSrcId`], even if the same macro
+    /// is expanded multiple times. Two spans with the same [`SpanSrcId`] therefore
+    /// originate from the same expansion instance. The id can be retrieved via
+    /// [`Span::src_id`](super::Span::src_id):
+    ///
+    /// ```ignore
+    /// // Check if both expressions come from the same macro expansion
+    /// if let (Some(a), Some(b)) = (expr_a.span().src_id(), expr_b.span().src_id()) {
+    ///     if a == b {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Span::is_same_source`](super::Span::is_same_source) can be used, to
+    /// compare the sources of two spans directly.
+    pub SpanSrcId: u32
 }
 
 new_id! {
@@ -15,7 +15,7 @@ use super::{SpanId, SpanSrcId, SymbolId};
 #[allow(clippy::exhaustive_enums)]
 #[derive(Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "driver-api", visibility::make(pub))]
-enum SpanSource<'ast> {
+pub(crate) enum SpanSource<'ast> {
     /// The span comes from a file
     File(ffi::FfiStr<'ast>),
     /// The span comes from a macro.
@@ -25,6 +25,93 @@ enum SpanSource<'ast> {
     Sugar(ffi::FfiStr<'ast>, SpanSrcId),
 }
 
+/// Information about the macro expansion, that a [`Span`] originates from.
+#[repr(C)]
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) struct MacroOrigin {
+    /// The span of the outermost macro call, which is written in a file.
+    pub call_site: SpanId,
+    /// The name of the expanded macro.
+    pub name: SymbolId,
+    /// `true`, if the expanded macro is defined in the current crate.
+    pub is_local: bool,
+}
+
+/// A line and column in a source file. Both values are 1-based.
+#[repr(C)]
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) struct FilePos {
+    pub line: u32,
+    pub col: u32,
+}
+
+/// The byte range of a line in a source file, relative to the [`SpanSource`].
+/// The end includes the trailing newline, if there is one.
+#[repr(C)]
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) struct LineBounds {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Information about a macro expansion. It can be retrieved with
+/// [`AstContext::macro_info`](crate::AstContext::macro_info) for the
+/// [`SpanSrcId`] of a span from a macro.
+#[repr(C)]
+pub struct MacroInfo<'ast> {
+    _lifetime: PhantomData<&'ast ()>,
+    name: SymbolId,
+    call_site: SpanId,
+    def_site: ffi::FfiOption<SpanId>,
+}
+
+impl<'ast> MacroInfo<'ast> {
+    /// The name of the expanded macro, like `vec` for `vec![]` or `Debug` for
+    /// `#[derive(Debug)]`.
+    pub fn name(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
+
+    /// The span of the macro call, which resulted in this expansion. This span
+    /// can itself originate from another macro expansion.
+    pub fn call_site_span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.call_site))
+    }
+
+    /// The span of the macro definition, if it's available.
+    pub fn def_site_span(&self) -> Option<&Span<'ast>> {
+        self.def_site.copy().map(|span| with_cx(self, |cx| cx.span(span)))
+    }
+}
+
+impl<'ast> std::fmt::Debug for MacroInfo<'ast> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MacroInfo")
+            .field("name", &self.name())
+            .field("call_site_span", &self.call_site_span())
+            .field("def_site_span", &self.def_site_span())
+            .finish()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> MacroInfo<'ast> {
+    pub fn new(name: SymbolId, call_site: SpanId, def_site: Option<SpanId>) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            name,
+            call_site,
+            def_site: def_site.into(),
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct Span<'ast> {
@@ -52,10 +139,102 @@ impl<'ast> Span<'ast> {
 
     /// Returns true, if both spans originate from the same source. For example, this can be the
     /// same source file or macro expansion.
+    ///
+    /// Spans from different expansions of the same macro have different sources.
+    /// Spans resulting from desugaring, like the `?` operator or `for` loops, are
+    /// only considered to have the same source if they originate from the same
+    /// desugaring:
+    ///
+    /// ```ignore
+    /// macro_rules! pair {
+    ///     ($a:expr) => { ($a, 1) };
+    /// }
+    ///
+    /// let a = pair!(0);
+    /// let b = pair!(0);
+    /// // The spans of the `1` literals in `a` and `b` are not from the same
+    /// // source, since they originate from different expansions. The `1` and
+    /// // the tuple expression in `a` are from the same source.
+    /// ```
     pub fn is_same_source(&self, other: &Span<'ast>) -> bool {
         self.source == other.source
     }
 
+    /// Returns the path of the file, that this span belongs to. The path is
+    /// relative to the root of the crate, if the file is located inside the
+    /// crate directory. `None` is returned for spans from macros.
+    pub fn file_name(&self) -> Option<&str> {
+        match self.source {
+            SpanSource::File(name) | SpanSource::Sugar(name, _) => Some(name.get()),
+            SpanSource::Macro(_) => None,
+        }
+    }
+
+    /// Returns the [`SpanSrcId`] of this span, if it originates from a macro
+    /// expansion or desugaring. Spans originating directly from a file return
+    /// `None`.
+    ///
+    /// The returned id can be compared, to check if two spans originate from
+    /// the same macro expansion.
+    pub fn src_id(&self) -> Option<SpanSrcId> {
+        match self.source {
+            SpanSource::File(_) => None,
+            SpanSource::Macro(id) | SpanSource::Sugar(_, id) => Some(*id),
+        }
+    }
+
+    /// Returns `true`, if the other span is fully contained in this span. Spans
+    /// from different sources never contain each other.
+    pub fn contains(&self, other: &Span<'ast>) -> bool {
+        self.is_same_source(other) && self.start <= other.start && other.end <= self.end
+    }
+
+    /// Returns `true`, if this and the other span have at least one byte in
+    /// common. Spans from different sources never overlap.
+    pub fn overlaps(&self, other: &Span<'ast>) -> bool {
+        self.is_same_source(other) && self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the smallest span, which covers both this and the other span.
+    /// `None` is returned, if the spans originate from different sources, like
+    /// different files or macro expansions. See [`Span::is_same_source`].
+    ///
+    /// This can be useful for suggestions, which replace the code of multiple
+    /// adjacent nodes.
+    pub fn merge(&self, other: &Span<'ast>) -> Option<Span<'ast>> {
+        if !self.is_same_source(other) {
+            return None;
+        }
+
+        Some(Span {
+            source: self.source,
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// Returns an empty span at the start of this span. This can be useful for
+    /// suggestions, which insert code before this span.
+    #[must_use]
+    pub fn shrink_to_lo(&self) -> Span<'ast> {
+        Span {
+            source: self.source,
+            start: self.start,
+            end: self.start,
+        }
+    }
+
+    /// Returns an empty span at the end of this span. This can be useful for
+    /// suggestions, which insert code after this span.
+    #[must_use]
+    pub fn shrink_to_hi(&self) -> Span<'ast> {
+        Span {
+            source: self.source,
+            start: self.end,
+            end: self.end,
+        }
+    }
+
     pub fn start(&self) -> usize {
         self.start
     }
@@ -64,6 +243,54 @@ impl<'ast> Span<'ast> {
         self.start = start;
     }
 
+    /// Returns the line and column of the start of this span. Both values are
+    /// 1-based, like in rustc's diagnostics. `None` is returned for spans from
+    /// macros, since their line and column are not meaningful.
+    pub fn start_line_col(&self) -> Option<(u32, u32)> {
+        self.pos_to_line_col(self.start)
+    }
+
+    /// Returns the line and column of the end of this span. Both values are
+    /// 1-based, like in rustc's diagnostics. `None` is returned for spans from
+    /// macros, since their line and column are not meaningful.
+    pub fn end_line_col(&self) -> Option<(u32, u32)> {
+        self.pos_to_line_col(self.end)
+    }
+
+    /// Returns a span, which covers all lines of this span completely. The start
+    /// is moved to the start of the first line and the end to the end of the
+    /// last line, including the trailing newline. This can be useful for
+    /// suggestions, which replace or remove entire lines while keeping the
+    /// indentation intact.
+    ///
+    /// `None` is returned for spans from macros, since their lines are not
+    /// meaningful.
+    pub fn expand_to_line(&self) -> Option<Span<'ast>> {
+        if self.is_from_macro() {
+            return None;
+        }
+
+        // The end is exclusive. A span ending directly after a newline should
+        // therefore not be extended to the following line.
+        let last_pos = self.end.saturating_sub(1).max(self.start);
+        with_cx(self, |cx| {
+            let first_line = cx.span_pos_to_line_bounds(self.source, self.start)?;
+            let last_line = cx.span_pos_to_line_bounds(self.source, last_pos)?;
+            Some(Span {
+                source: self.source,
+                start: first_line.start,
+                end: last_line.end,
+            })
+        })
+    }
+
+    fn pos_to_line_col(&self, pos: usize) -> Option<(u32, u32)> {
+        if self.is_from_macro() {
+            return None;
+        }
+        with_cx(self, |cx| cx.span_pos_to_file_pos(self.source, pos)).map(|pos| (pos.line, pos.col))
+    }
+
     pub fn end(&self) -> usize {
         self.end
     }
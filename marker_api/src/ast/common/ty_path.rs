@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+/// A path to a type definition, used by
+/// [`AstContext::resolve_ty_ids`](crate::AstContext::resolve_ty_ids). The
+/// first segment is the crate name, or `crate` for the current crate.
+///
+/// A path can be created from a string, or by adding the segments one by one:
+///
+/// ```
+/// # use marker_api::ast::TyPath;
+/// let from_str: TyPath<'_> = "std::vec::Vec".into();
+/// let built = TyPath::new("std").segment("vec").segment("Vec");
+/// assert_eq!(from_str, built);
+/// ```
+///
+/// The path only identifies the type definition. Generic arguments are
+/// therefore not part of it.
+///
+/// Every segment has to be a non-empty identifier, like `std` or `Vec`. The
+/// segments are validated when the path is built. [`TyPath::new`] and
+/// [`TyPath::segment`] panic on invalid segments. A string with an invalid
+/// segment, like `"std::::Vec"`, results in a path without segments, which
+/// doesn't resolve to any type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TyPath<'a> {
+    segments: Vec<&'a str>,
+}
+
+impl<'a> TyPath<'a> {
+    /// Creates a new path, starting with the given crate name.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the crate name is not a valid identifier.
+    pub fn new(krate: &'a str) -> Self {
+        assert_valid_segment(krate);
+        Self { segments: vec![krate] }
+    }
+
+    /// Appends the given segment to this path.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the segment is not a valid identifier.
+    #[must_use]
+    pub fn segment(mut self, name: &'a str) -> Self {
+        assert_valid_segment(name);
+        self.segments.push(name);
+        self
+    }
+
+    /// Returns the segments of this path, starting with the crate name.
+    pub fn segments(&self) -> &[&'a str] {
+        &self.segments
+    }
+}
+
+fn is_valid_segment(segment: &str) -> bool {
+    let mut chars = segment.chars();
+    chars
+        .next()
+        .map_or(false, |first| first.is_alphabetic() || first == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn assert_valid_segment(segment: &str) {
+    assert!(
+        is_valid_segment(segment),
+        "`{segment}` is not a valid segment of a type path"
+    );
+}
+
+impl<'a> From<&'a str> for TyPath<'a> {
+    fn from(path: &'a str) -> Self {
+        let segments: Vec<_> = path.split("::").collect();
+        if !segments.iter().all(|segment| is_valid_segment(segment)) {
+            return Self { segments: vec![] };
+        }
+        Self { segments }
+    }
+}
+
+impl<'a> From<&'a String> for TyPath<'a> {
+    fn from(path: &'a String) -> Self {
+        path.as_str().into()
+    }
+}
+
+impl<'a> Display for TyPath<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.segments.join("::"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ty_path_from_str() {
+        let path: TyPath<'_> = "crate::module::Item".into();
+        assert_eq!(path.segments(), ["crate", "module", "Item"]);
+
+        let owned = String::from("std::vec::Vec");
+        assert_eq!(TyPath::from(&owned), TyPath::new("std").segment("vec").segment("Vec"));
+    }
+
+    #[test]
+    fn test_ty_path_from_invalid_str() {
+        assert!(TyPath::from("").segments().is_empty());
+        assert!(TyPath::from("std::::Vec").segments().is_empty());
+        assert!(TyPath::from("std::vec::Vec<u8>").segments().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "`` is not a valid segment of a type path")]
+    fn test_ty_path_empty_segment() {
+        let _ = TyPath::new("std").segment("");
+    }
+
+    #[test]
+    #[should_panic(expected = "`Vec<u8>` is not a valid segment of a type path")]
+    fn test_ty_path_generic_segment() {
+        let _ = TyPath::new("std").segment("vec").segment("Vec<u8>");
+    }
+}
@@ -91,6 +91,16 @@ impl<'ast> ExprKind<'ast> {
     impl_expr_kind_fn!(ExprKind: id() -> ExprId);
     impl_expr_kind_fn!(ExprKind: ty() -> SemTyKind<'ast>);
     impl_expr_kind_fn!(ExprKind: precedence() -> ExprPrecedence);
+
+    /// Returns `true`, if this expression never returns control to the surrounding
+    /// code. This is the case for expressions with the never type
+    /// [`!`](prim@never), like `return`, `break`, `loop {}` without a `break`,
+    /// calls to diverging functions or the expansion of macros like [`panic!`].
+    ///
+    /// Code directly following a diverging expression is unreachable.
+    pub fn is_diverging(&self) -> bool {
+        matches!(self.ty(), SemTyKind::Never(..))
+    }
 }
 
 impl Sealed for ExprKind<'_> {}
@@ -343,8 +353,8 @@ use impl_expr_data;
 /// An expression that is evaluated at compile time. These show up in array
 /// indices and constant generics.
 ///
-/// Marker currently doesn't provide a way to calculate the result of a constant
-/// expression.
+/// The value of the expression can be retrieved with
+/// [`AstContext::resolve_const_value`](crate::context::AstContext::resolve_const_value).
 #[repr(C)]
 #[derive(Debug)]
 pub struct ConstExpr<'ast> {
@@ -375,12 +385,12 @@ mod test {
         // These sizes are allowed to change, this is just a check to have a
         // general overview and to prevent accidental changes
         assert_eq!(40, size_of::<IntLitExpr<'_>>(), "IntLitExpr<'_>");
-        assert_eq!(32, size_of::<FloatLitExpr<'_>>(), "FloatLitExpr<'_>");
+        assert_eq!(40, size_of::<FloatLitExpr<'_>>(), "FloatLitExpr<'_>");
         assert_eq!(48, size_of::<StrLitExpr<'_>>(), "StrLitExpr<'_>");
         assert_eq!(24, size_of::<CharLitExpr<'_>>(), "CharLitExpr<'_>");
         assert_eq!(24, size_of::<BoolLitExpr<'_>>(), "BoolLitExpr<'_>");
         assert_eq!(96, size_of::<BlockExpr<'_>>(), "BlockExpr<'_>");
-        assert_eq!(72, size_of::<ClosureExpr<'_>>(), "ClosureExpr<'_>");
+        assert_eq!(80, size_of::<ClosureExpr<'_>>(), "ClosureExpr<'_>");
         assert_eq!(40, size_of::<UnaryOpExpr<'_>>(), "UnaryOpExpr<'_>");
         assert_eq!(40, size_of::<RefExpr<'_>>(), "RefExpr<'_>");
         assert_eq!(56, size_of::<BinaryOpExpr<'_>>(), "BinaryOpExpr<'_>");
@@ -389,7 +399,7 @@ mod test {
         assert_eq!(48, size_of::<AsExpr<'_>>(), "AsExpr<'_>");
         assert_eq!(96, size_of::<PathExpr<'_>>(), "PathExpr<'_>");
         assert_eq!(48, size_of::<CallExpr<'_>>(), "CallExpr<'_>");
-        assert_eq!(80, size_of::<MethodExpr<'_>>(), "MethodExpr<'_>");
+        assert_eq!(104, size_of::<MethodExpr<'_>>(), "MethodExpr<'_>");
         assert_eq!(56, size_of::<ArrayExpr<'_>>(), "ArrayExpr<'_>");
         assert_eq!(32, size_of::<TupleExpr<'_>>(), "TupleExpr<'_>");
         assert_eq!(136, size_of::<CtorExpr<'_>>(), "CtorExpr<'_>");
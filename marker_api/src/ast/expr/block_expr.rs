@@ -1,5 +1,5 @@
 use crate::{
-    ast::{pat::PatKind, stmt::StmtKind, ty::SynTyKind, BodyId, Ident, Safety, Span, SpanId, Syncness},
+    ast::{pat::PatKind, stmt::StmtKind, ty::SynTyKind, BodyId, Constness, Ident, Safety, Span, SpanId, Syncness},
     context::with_cx,
     ffi::{FfiOption, FfiSlice},
 };
@@ -36,7 +36,9 @@ use super::{CommonExprData, ExprKind};
 /// clear by linking directly to the [`BlockExpr`] struct or calling it a *block*.
 ///
 /// This expression also represents async blocks, the internal desugar used by
-/// rustc is resugared for this.
+/// rustc is resugared for this. The qualifiers of a block, like `unsafe`, `async`
+/// and `const`, can be checked with [`BlockExpr::is_unsafe`], [`BlockExpr::is_async`]
+/// and [`BlockExpr::is_const`].
 #[repr(C)]
 #[derive(Debug)]
 pub struct BlockExpr<'ast> {
@@ -46,6 +48,7 @@ pub struct BlockExpr<'ast> {
     label: FfiOption<Ident<'ast>>,
     safety: Safety,
     syncness: Syncness,
+    constness: Constness,
     capture_kind: CaptureKind,
 }
 
@@ -70,10 +73,29 @@ impl<'ast> BlockExpr<'ast> {
         self.safety
     }
 
+    /// Returns `true`, if this is an `unsafe` block.
+    pub fn is_unsafe(&self) -> bool {
+        self.safety.is_unsafe()
+    }
+
     pub fn syncness(&self) -> Syncness {
         self.syncness
     }
 
+    /// Returns `true`, if this is an `async` block.
+    pub fn is_async(&self) -> bool {
+        self.syncness.is_async()
+    }
+
+    pub fn constness(&self) -> Constness {
+        self.constness
+    }
+
+    /// Returns `true`, if this is an inline `const` block, like `const { 1 + 2 }`.
+    pub fn is_const(&self) -> bool {
+        self.constness.is_const()
+    }
+
     /// The capture kind of this block. For normal blocks, this will always be
     /// [`CaptureKind::Default`], which in this context means no capture at all.
     /// Async blocks are special, as they can capture values by move, indicated
@@ -98,6 +120,7 @@ super::impl_expr_data!(BlockExpr<'ast>, Block);
 
 #[cfg(feature = "driver-api")]
 impl<'ast> BlockExpr<'ast> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         data: CommonExprData<'ast>,
         stmts: &'ast [StmtKind<'ast>],
@@ -105,6 +128,7 @@ impl<'ast> BlockExpr<'ast> {
         label: Option<Ident<'ast>>,
         safety: Safety,
         syncness: Syncness,
+        constness: Constness,
         capture_kind: CaptureKind,
     ) -> Self {
         Self {
@@ -114,6 +138,7 @@ impl<'ast> BlockExpr<'ast> {
             label: label.into(),
             safety,
             syncness,
+            constness,
             capture_kind,
         }
     }
@@ -133,11 +158,16 @@ impl<'ast> BlockExpr<'ast> {
 ///     print("Marker")
 /// };
 /// ```
+///
+/// Closures can additionally be marked as `async` or `static`. Both of these
+/// qualifiers are currently unstable.
 #[repr(C)]
 #[derive(Debug)]
 pub struct ClosureExpr<'ast> {
     data: CommonExprData<'ast>,
     capture_kind: CaptureKind,
+    syncness: Syncness,
+    is_static: bool,
     params: FfiSlice<'ast, ClosureParam<'ast>>,
     return_ty: FfiOption<SynTyKind<'ast>>,
     body_id: BodyId,
@@ -148,6 +178,28 @@ impl<'ast> ClosureExpr<'ast> {
         self.capture_kind
     }
 
+    /// Returns `true`, if this closure captures values by move, indicated by
+    /// the `move` keyword.
+    pub fn is_move(&self) -> bool {
+        matches!(self.capture_kind, CaptureKind::Move)
+    }
+
+    pub fn syncness(&self) -> Syncness {
+        self.syncness
+    }
+
+    /// Returns `true`, if this is an `async` closure, like `async |x| x.await`.
+    pub fn is_async(&self) -> bool {
+        self.syncness.is_async()
+    }
+
+    /// Returns `true`, if this closure is marked as `static`. These closures
+    /// are used to define generators, which can't be moved once they have been
+    /// started.
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+
     pub fn params(&self) -> &'ast [ClosureParam<'ast>] {
         self.params.get()
     }
@@ -168,6 +220,8 @@ impl<'ast> ClosureExpr<'ast> {
     pub fn new(
         data: CommonExprData<'ast>,
         capture_kind: CaptureKind,
+        syncness: Syncness,
+        is_static: bool,
         params: &'ast [ClosureParam<'ast>],
         return_ty: Option<SynTyKind<'ast>>,
         body_id: BodyId,
@@ -175,6 +229,8 @@ impl<'ast> ClosureExpr<'ast> {
         Self {
             data,
             capture_kind,
+            syncness,
+            is_static,
             params: params.into(),
             return_ty: return_ty.into(),
             body_id,
@@ -1,4 +1,8 @@
-use crate::{ast::AstPathSegment, ffi::FfiSlice};
+use crate::{
+    ast::{generic::SemGenericArgKind, AstPathSegment, ItemId},
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice},
+};
 
 use super::{CommonExprData, ExprKind};
 
@@ -61,6 +65,7 @@ pub struct MethodExpr<'ast> {
     receiver: ExprKind<'ast>,
     method: AstPathSegment<'ast>,
     args: FfiSlice<'ast, ExprKind<'ast>>,
+    turbofish: FfiOption<FfiSlice<'ast, SemGenericArgKind<'ast>>>,
 }
 
 impl<'ast> MethodExpr<'ast> {
@@ -73,19 +78,34 @@ impl<'ast> MethodExpr<'ast> {
         &self.method
     }
 
-    // FIXME(xFrednet): Add this method again, once `resolve_method_target` is
-    // supported by rustc's driver
-    //
-    // /// This method resolves the [`ItemId`] of the method being called by this
-    // /// expression.
-    // pub fn resolve(&self) -> ItemId {
-    //     with_cx(self, |cx| cx.resolve_method_target(self.data.id))
-    // }
+    /// This method resolves the [`ItemId`] of the method being called by this
+    /// expression. See [`AstContext::callee_of`](crate::context::AstContext::callee_of)
+    /// for more information.
+    pub fn resolve(&self) -> Option<ItemId> {
+        with_cx(self, |cx| cx.callee_of(self.data.id))
+    }
 
     /// The arguments given to the operand.
     pub fn args(&self) -> &[ExprKind<'ast>] {
         self.args.get()
     }
+
+    /// Returns the semantic generic arguments of the method, if they have been
+    /// explicitly specified with the turbofish syntax, or `None`, if no
+    /// turbofish was given. Inferred arguments, like `_`, are resolved to the
+    /// inferred types. Lifetime arguments are not included. The syntactic
+    /// arguments are available via the generics of [`MethodExpr::method`].
+    ///
+    /// ```
+    /// # let vec = vec![1, 2, 3];
+    /// let _: Vec<_> = vec.iter().collect();
+    /// //                         ^^^^^^^ A method call without a turbofish
+    /// let _ = vec.iter().collect::<Vec<_>>();
+    /// //                        ^^^^^^^^^^ The turbofish with generic arguments
+    /// ```
+    pub fn turbofish(&self) -> Option<&'ast [SemGenericArgKind<'ast>]> {
+        self.turbofish.get().map(FfiSlice::get)
+    }
 }
 
 super::impl_expr_data!(MethodExpr<'ast>, Method);
@@ -97,12 +117,14 @@ impl<'ast> MethodExpr<'ast> {
         receiver: ExprKind<'ast>,
         method: AstPathSegment<'ast>,
         args: &'ast [ExprKind<'ast>],
+        turbofish: Option<&'ast [SemGenericArgKind<'ast>]>,
     ) -> Self {
         Self {
             data,
             receiver,
             method,
             args: args.into(),
+            turbofish: turbofish.map(Into::into).into(),
         }
     }
 }
@@ -57,10 +57,61 @@ impl<'ast> IfExpr<'ast> {
     pub fn els(&self) -> Option<ExprKind<'ast>> {
         self.els.copy()
     }
+
+    /// Returns an iterator over all branches of this `if` expression. Nested
+    /// `else if` expressions are flattened into the iterator, which means that
+    /// only the last branch can be a [`IfBranch::Else`].
+    ///
+    /// ```
+    /// # let num = 5;
+    /// if num == 1 {
+    ///     // IfBranch::Condition
+    /// } else if num == 2 {
+    ///     // IfBranch::Condition
+    /// } else {
+    ///     // IfBranch::Else
+    /// }
+    /// ```
+    pub fn branches(&self) -> impl Iterator<Item = IfBranch<'ast>> {
+        let mut next = Some(IfBranch::Condition {
+            condition: self.condition,
+            then: self.then,
+        });
+        let mut els = self.els.copy();
+        std::iter::from_fn(move || {
+            let current = next.take()?;
+            next = match els.take() {
+                Some(ExprKind::If(if_expr)) => {
+                    els = if_expr.els();
+                    Some(IfBranch::Condition {
+                        condition: if_expr.condition(),
+                        then: if_expr.then(),
+                    })
+                },
+                Some(expr) => Some(IfBranch::Else(expr)),
+                None => None,
+            };
+            Some(current)
+        })
+    }
 }
 
 super::impl_expr_data!(IfExpr<'ast>, If);
 
+/// A single branch of an [`IfExpr`] chain, see [`IfExpr::branches`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum IfBranch<'ast> {
+    /// A branch, which is only executed if the condition is `true`. This is
+    /// the first branch of an `if` expression or an `else if` branch.
+    Condition {
+        condition: ExprKind<'ast>,
+        then: ExprKind<'ast>,
+    },
+    /// The final `else` branch, without a condition.
+    Else(ExprKind<'ast>),
+}
+
 #[cfg(feature = "driver-api")]
 impl<'ast> IfExpr<'ast> {
     pub fn new(
@@ -182,7 +233,9 @@ impl<'ast> MatchExpr<'ast> {
 ///
 /// //  vvv The pattern of the arm
 ///     [x] if *x == 1 => println!("found a one"),
-/// //         ^^^^^^^ The guard expression of the arm
+/// //         ^^^^^^^ ^^ The arrow separating the pattern and guard from the expression
+/// //         |
+/// //         The guard expression of the arm
 ///
 /// //   v A wildcard pattern used as a default branch
 ///      _ => {
@@ -197,6 +250,7 @@ pub struct MatchArm<'ast> {
     span: SpanId,
     pat: PatKind<'ast>,
     guard: FfiOption<ExprKind<'ast>>,
+    arrow_span: SpanId,
     expr: ExprKind<'ast>,
 }
 
@@ -213,6 +267,17 @@ impl<'ast> MatchArm<'ast> {
         self.guard.copy()
     }
 
+    /// The span of the `=>` token, which separates the pattern and optional
+    /// guard from the arm expression.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will return the span between the pattern or guard and the arm expression,
+    ///   if the `=>` token can't be found in the source code. This can be the case for arms created
+    ///   by macros.
+    pub fn arrow_span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.arrow_span))
+    }
+
     pub fn expr(&self) -> ExprKind<'ast> {
         self.expr
     }
@@ -222,11 +287,18 @@ impl<'ast> MatchArm<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> MatchArm<'ast> {
-    pub fn new(span: SpanId, pat: PatKind<'ast>, guard: Option<ExprKind<'ast>>, expr: ExprKind<'ast>) -> Self {
+    pub fn new(
+        span: SpanId,
+        pat: PatKind<'ast>,
+        guard: Option<ExprKind<'ast>>,
+        arrow_span: SpanId,
+        expr: ExprKind<'ast>,
+    ) -> Self {
         Self {
             span,
             pat,
             guard: guard.into(),
+            arrow_span,
             expr,
         }
     }
@@ -419,6 +491,9 @@ pub struct LoopExpr<'ast> {
 }
 
 impl<'ast> LoopExpr<'ast> {
+    /// The optional label of this loop, which can be targeted by `break` and
+    /// `continue` expressions. The name of the returned [`Ident`] includes the
+    /// leading `'`, like `'label`.
     pub fn label(&self) -> Option<&Ident<'ast>> {
         self.label.get()
     }
@@ -466,6 +541,9 @@ pub struct WhileExpr<'ast> {
 }
 
 impl<'ast> WhileExpr<'ast> {
+    /// The optional label of this loop, which can be targeted by `break` and
+    /// `continue` expressions. The name of the returned [`Ident`] includes the
+    /// leading `'`, like `'label`.
     pub fn label(&self) -> Option<&Ident<'ast>> {
         self.label.get()
     }
@@ -523,6 +601,9 @@ pub struct ForExpr<'ast> {
 }
 
 impl<'ast> ForExpr<'ast> {
+    /// The optional label of this loop, which can be targeted by `break` and
+    /// `continue` expressions. The name of the returned [`Ident`] includes the
+    /// leading `'`, like `'label`.
     pub fn label(&self) -> Option<&Ident<'ast>> {
         self.label.get()
     }
@@ -27,13 +27,27 @@ pub struct ArrayExpr<'ast> {
 }
 
 impl<'ast> ArrayExpr<'ast> {
+    /// Returns the element expressions of this array. A repeat array expression,
+    /// like `[6; 3]`, will only return the repeated operand.
     pub fn elements(&self) -> &[ExprKind<'ast>] {
         self.elements.get()
     }
 
+    /// Returns the length operand of repeat array expressions, like `[6; 3]`.
     pub fn len(&self) -> Option<&ConstExpr<'ast>> {
         self.len.get()
     }
+
+    /// Returns the syntactic form of this array expression, which can either
+    /// be a list of elements or a repeated operand.
+    pub fn kind(&'ast self) -> ArrayExprKind<'ast> {
+        match (self.len.get(), self.elements.get().first()) {
+            (Some(count), Some(value)) => ArrayExprKind::Repeat { value: *value, count },
+            _ => ArrayExprKind::List {
+                elements: self.elements.get(),
+            },
+        }
+    }
 }
 
 super::impl_expr_data!(
@@ -55,6 +69,26 @@ impl<'ast> ArrayExpr<'ast> {
     }
 }
 
+/// The syntactic form of an [`ArrayExpr`].
+///
+/// ```
+/// //            vvvvvvvvvvvv A list array expression
+/// let array_1 = [1, 2, 3, 4];
+/// //            vvvvvv A repeat array expression
+/// let array_2 = [6; 3];
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum ArrayExprKind<'ast> {
+    /// An array expression listing all elements, like `[1, 2, 3, 4]`.
+    List { elements: &'ast [ExprKind<'ast>] },
+    /// An array expression repeating a single operand, like `[6; 3]`.
+    Repeat {
+        value: ExprKind<'ast>,
+        count: &'ast ConstExpr<'ast>,
+    },
+}
+
 /// An expression used to construct a tuple.
 ///
 /// ```
@@ -152,13 +186,30 @@ impl<'ast> CtorExpr<'ast> {
         &self.path
     }
 
+    /// The explicitly named fields of this constructor. Fields which are taken
+    /// from the [base expression](`CtorExpr::base`) are not included.
     pub fn fields(&self) -> &'ast [CtorField<'ast>] {
         self.fields.get()
     }
 
+    /// The base expression of the struct update syntax, if present.
+    ///
+    /// ```
+    /// # #[derive(Default)]
+    /// # struct FieldStruct { a: u32, b: u32 }
+    /// let _ = FieldStruct { a: 10, ..FieldStruct::default() };
+    /// //                             ^^^^^^^^^^^^^^^^^^^^^^ The base expression
+    /// ```
     pub fn base(&self) -> Option<ExprKind<'ast>> {
         self.base.copy()
     }
+
+    /// Returns `true`, if this constructor uses the struct update syntax
+    /// `..base`. All fields, which are not explicitly named in
+    /// [`CtorExpr::fields`], are taken from the [base expression](`CtorExpr::base`).
+    pub fn is_struct_update(&self) -> bool {
+        self.base.get().is_some()
+    }
 }
 
 super::impl_expr_data!(
@@ -237,10 +288,12 @@ pub struct RangeExpr<'ast> {
 }
 
 impl<'ast> RangeExpr<'ast> {
+    #[deprecated(note = "use `RangeExpr::kind()` instead, which can be matched exhaustively")]
     pub fn start(&self) -> Option<ExprKind<'ast>> {
         self.start.copy()
     }
 
+    #[deprecated(note = "use `RangeExpr::kind()` instead, which can be matched exhaustively")]
     pub fn end(&self) -> Option<ExprKind<'ast>> {
         self.end.copy()
     }
@@ -248,10 +301,50 @@ impl<'ast> RangeExpr<'ast> {
     pub fn is_inclusive(&self) -> bool {
         self.is_inclusive
     }
+
+    /// Returns the kind of this range, which also provides the start and end
+    /// expressions.
+    pub fn kind(&self) -> RangeKind<'ast> {
+        match (self.start.copy(), self.end.copy(), self.is_inclusive) {
+            (Some(start), Some(end), false) => RangeKind::HalfOpen { start, end },
+            (Some(start), Some(end), true) => RangeKind::Closed { start, end },
+            (Some(start), None, _) => RangeKind::From { start },
+            (None, Some(end), false) => RangeKind::To { end },
+            (None, Some(end), true) => RangeKind::ToInclusive { end },
+            (None, None, _) => RangeKind::Full,
+        }
+    }
 }
 
 super::impl_expr_data!(RangeExpr<'ast>, Range);
 
+/// The kind of a [`RangeExpr`], holding the start and end expressions.
+///
+/// ```
+/// 1..9;  // `RangeKind::HalfOpen`
+/// 0..=1; // `RangeKind::Closed`
+/// 3..;   // `RangeKind::From`
+/// ..5;   // `RangeKind::To`
+/// ..=5;  // `RangeKind::ToInclusive`
+/// ..;    // `RangeKind::Full`
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum RangeKind<'ast> {
+    /// A range with an exclusive end, like `start..end`
+    HalfOpen { start: ExprKind<'ast>, end: ExprKind<'ast> },
+    /// A range with an inclusive end, like `start..=end`
+    Closed { start: ExprKind<'ast>, end: ExprKind<'ast> },
+    /// A range without an end, like `start..`
+    From { start: ExprKind<'ast> },
+    /// A range without a start and an exclusive end, like `..end`
+    To { end: ExprKind<'ast> },
+    /// A range without a start and an inclusive end, like `..=end`
+    ToInclusive { end: ExprKind<'ast> },
+    /// A range without a start or end, like `..`
+    Full,
+}
+
 #[cfg(feature = "driver-api")]
 impl<'ast> RangeExpr<'ast> {
     pub fn new(
@@ -72,6 +72,7 @@ impl<'ast> CharLitExpr<'ast> {
 pub struct FloatLitExpr<'ast> {
     data: CommonExprData<'ast>,
     value: f64,
+    raw_value: SymbolId,
     suffix: FfiOption<FloatSuffix>,
 }
 
@@ -87,6 +88,15 @@ impl<'ast> FloatLitExpr<'ast> {
         self.value
     }
 
+    /// The literal value as written in the source code, with the suffix and
+    /// all underscores removed. A literal like `1_000.5e3_f32` will return
+    /// `"1000.5e3"`. This can be used to create suggestions, without
+    /// reformatting the float value. The [`Span`](crate::ast::Span) snippet
+    /// can be used to retrieve the original text.
+    pub fn raw_value(&self) -> &str {
+        with_cx(self, |cx| cx.symbol_str(self.raw_value))
+    }
+
     /// The suffix if it has been defined by the user. Use the
     /// [`ExprData::ty`](`super::ExprData::ty`) method to determine the type,
     /// if it hasn't been specified in the suffix
@@ -105,19 +115,23 @@ super::impl_expr_data!(
 
 #[cfg(feature = "driver-api")]
 impl<'ast> FloatLitExpr<'ast> {
-    pub fn new(data: CommonExprData<'ast>, value: f64, suffix: Option<FloatSuffix>) -> Self {
+    pub fn new(data: CommonExprData<'ast>, value: f64, raw_value: SymbolId, suffix: Option<FloatSuffix>) -> Self {
         Self {
             data,
             value,
+            raw_value,
             suffix: suffix.into(),
         }
     }
 }
 
+/// The suffix of a float literal, like the `f32` in `1.0f32` or `1.0_f32`.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum FloatSuffix {
+    /// The `f32` suffix, like `1.0f32`
     F32,
+    /// The `f64` suffix, like `1.0f64`
     F64,
 }
 
@@ -168,20 +182,36 @@ impl<'ast> IntLitExpr<'ast> {
     }
 }
 
+/// The suffix of an integer literal, like the `u8` in `42u8` or `42_u8`. The
+/// suffix is stored as part of the [`IntLitExpr`] and directly reflects what
+/// was written in the source code. Unsuffixed literals like `42` have no suffix,
+/// even if their type is known.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IntSuffix {
+    /// The `isize` suffix, like `42isize`
     Isize,
+    /// The `i8` suffix, like `42i8`
     I8,
+    /// The `i16` suffix, like `42i16`
     I16,
+    /// The `i32` suffix, like `42i32`
     I32,
+    /// The `i64` suffix, like `42i64`
     I64,
+    /// The `i128` suffix, like `42i128`
     I128,
+    /// The `usize` suffix, like `42usize`
     Usize,
+    /// The `u8` suffix, like `42u8`
     U8,
+    /// The `u16` suffix, like `42u16`
     U16,
+    /// The `u32` suffix, like `42u32`
     U32,
+    /// The `u64` suffix, like `42u64`
     U64,
+    /// The `u128` suffix, like `42u128`
     U128,
 }
 
@@ -189,20 +219,29 @@ pub enum IntSuffix {
 #[derive(Debug)]
 pub struct StrLitExpr<'ast> {
     data: CommonExprData<'ast>,
-    is_raw: bool,
+    /// The number of `#` characters used by a raw string literal or `None` for
+    /// standard string literals.
+    raw_hashes: FfiOption<u8>,
     str_data: StrLitData<'ast>,
 }
 
 impl<'ast> StrLitExpr<'ast> {
     /// Returns `true`, if this is a raw string literal, like `r#"Hello World!"#`
     pub fn is_raw_lit(&self) -> bool {
-        self.is_raw
+        self.raw_hashes.is_some()
+    }
+
+    /// Returns the number of `#` characters, used to delimit a raw string literal.
+    /// The literal `r##"Hello World!"##` will return `2`, `r"Hello World!"` will
+    /// return `0`. Standard string literals always return `0`.
+    pub fn raw_hashes(&self) -> u32 {
+        self.raw_hashes.copy().map_or(0, u32::from)
     }
 
     /// Returns `true`, if this is a standard string literal, like `"Hello World!"`.
     /// This type of string is also sometimes referred to as *Cooked*.
     pub fn is_standard_lit(&self) -> bool {
-        !self.is_raw
+        !self.is_raw_lit()
     }
 
     /// This returns `true`, if the literal is a byte string literal like `b"Hello\0"`
@@ -240,8 +279,12 @@ super::impl_expr_data!(
 
 #[cfg(feature = "driver-api")]
 impl<'ast> StrLitExpr<'ast> {
-    pub fn new(data: CommonExprData<'ast>, is_raw: bool, str_data: StrLitData<'ast>) -> Self {
-        Self { data, is_raw, str_data }
+    pub fn new(data: CommonExprData<'ast>, raw_hashes: Option<u8>, str_data: StrLitData<'ast>) -> Self {
+        Self {
+            data,
+            raw_hashes: raw_hashes.into(),
+            str_data,
+        }
     }
 }
 
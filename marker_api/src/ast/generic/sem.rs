@@ -25,7 +25,7 @@ use crate::{ast::ty::SemTyKind, ffi::FfiSlice};
 /// See:
 /// * [`SynGenericParams`][super::SynGenericParams]
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemGenericArgs<'ast> {
     args: FfiSlice<'ast, SemGenericArgKind<'ast>>,
 }
@@ -52,7 +52,7 @@ impl<'ast> SemGenericArgs<'ast> {
 /// See: <https://doc.rust-lang.org/stable/reference/paths.html>
 #[repr(C)]
 #[non_exhaustive]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 #[cfg_attr(feature = "driver-api", derive(Clone))]
 pub enum SemGenericArgKind<'ast> {
     /// A type as a generic argument, like this:
@@ -8,7 +8,7 @@ use crate::ast::{ty::SemTyKind, ConstValue, ItemId};
 /// //                      ^^^^^^^^
 /// ```
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemBindingArg<'ast> {
     binding_target: ItemId,
     ty: SemTyKind<'ast>,
@@ -41,7 +41,7 @@ impl<'ast> SemBindingArg<'ast> {
 }
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct SemConstArg<'ast> {
     value: ConstValue<'ast>,
 }
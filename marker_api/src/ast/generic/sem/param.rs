@@ -4,7 +4,7 @@ use super::SemGenericArgs;
 
 /// A semantic trait bound used by [`SemTraitTy`](`crate::ast::ty::SemTraitObjTy`)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemTraitBound<'ast> {
     /// This is used for relaxed type bounds like `?Size`. This is probably not
     /// the best representation. Rustc uses a `TraitBoundModifier` enum which
@@ -126,9 +126,46 @@ impl<'ast> SynGenericParams<'ast> {
         self.params.get()
     }
 
+    /// Returns an iterator over all type parameters, like `T` in `fn foo<T>()`.
+    pub fn ty_params(&self) -> impl Iterator<Item = &'ast SynTyParam<'ast>> + 'ast {
+        self.params.get().iter().filter_map(|param| match param {
+            SynGenericParamKind::Ty(ty) => Some(*ty),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over all lifetime parameters, like `'a` in `fn foo<'a>()`.
+    pub fn lifetime_params(&self) -> impl Iterator<Item = &'ast SynLifetimeParam<'ast>> + 'ast {
+        self.params.get().iter().filter_map(|param| match param {
+            SynGenericParamKind::Lifetime(lt) => Some(*lt),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over all const parameters, like `N` in
+    /// `fn foo<const N: usize>()`.
+    pub fn const_params(&self) -> impl Iterator<Item = &'ast SynConstParam<'ast>> + 'ast {
+        self.params.get().iter().filter_map(|param| match param {
+            SynGenericParamKind::Const(con) => Some(*con),
+            _ => None,
+        })
+    }
+
+    /// Returns the bounds of the generic parameters. This includes bounds
+    /// declared with the parameter, like `T: Copy` in `fn foo<T: Copy>()`, and
+    /// bounds declared in the `where` clause of the item.
+    ///
+    /// See [`SynGenericParams::where_clauses`] to only get the clauses of the
+    /// `where` clause.
     pub fn clauses(&self) -> &'ast [SynWhereClauseKind<'ast>] {
         self.clauses.get()
     }
+
+    /// Returns an iterator over the clauses, which were written in the `where`
+    /// clause of the item.
+    pub fn where_clauses(&self) -> impl Iterator<Item = &'ast SynWhereClauseKind<'ast>> + 'ast {
+        self.clauses.get().iter().filter(|clause| clause.in_where_clause())
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -1,9 +1,11 @@
 use crate::{
     ast::{expr::ConstExpr, ty::SynTyKind, Span, SpanId, SymbolId, TraitRef},
     context::with_cx,
+    ffi::FfiOption,
 };
 
 use super::super::Lifetime;
+use super::SynGenericParams;
 
 /// The syntactic representation of a generic argument, like this:
 ///
@@ -187,11 +189,18 @@ pub struct SynTraitBound<'ast> {
     /// the best representation. Rustc uses a `TraitBoundModifier` enum which
     /// is interesting, but would only have two states right now.
     is_relaxed: bool,
+    params: FfiOption<SynGenericParams<'ast>>,
     trait_ref: TraitRef<'ast>,
     span: SpanId,
 }
 
 impl<'ast> SynTraitBound<'ast> {
+    /// Additional lifetime parameters introduced for this bound with a `for`,
+    /// like the `'a` in `T: for<'a> Fn(&'a u8)`.
+    pub fn params(&self) -> Option<&SynGenericParams<'ast>> {
+        self.params.get()
+    }
+
     pub fn trait_ref(&self) -> &TraitRef<'ast> {
         &self.trait_ref
     }
@@ -211,9 +220,15 @@ impl<'ast> SynTraitBound<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SynTraitBound<'ast> {
-    pub fn new(is_relaxed: bool, trait_ref: TraitRef<'ast>, span: SpanId) -> Self {
+    pub fn new(
+        is_relaxed: bool,
+        params: Option<SynGenericParams<'ast>>,
+        trait_ref: TraitRef<'ast>,
+        span: SpanId,
+    ) -> Self {
         Self {
             is_relaxed,
+            params: params.into(),
             trait_ref,
             span,
         }
@@ -70,12 +70,16 @@ pub trait SynGenericParamData<'ast>: Debug + Sealed {
     // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
 }
 
-/// A type parameter with optional bounds like `T` and `U` in this example:
+/// A type parameter with optional bounds and an optional default like `T`, `U`
+/// and `V` in this example:
 ///
 /// ```
-/// //     v
-/// fn foo<T, U: Copy + 'static>() {}
-/// //        ^^^^^^^^^^^^^^^^^
+/// # use std::marker::PhantomData;
+/// //          v
+/// struct Item<T, U: Copy + 'static, V: Clone = String> {
+/// //             ^^^^^^^^^^^^^^^^^  ^^^^^^^^^^^^^^^^^
+/// #    _data: PhantomData<(T, U, V)>,
+/// }
 /// ```
 #[repr(C)]
 #[derive(Debug)]
@@ -84,16 +88,26 @@ pub struct SynTyParam<'ast> {
     id: GenericId,
     name: SymbolId,
     span: FfiOption<SpanId>,
+    bounds: FfiSlice<'ast, SynTyParamBound<'ast>>,
+    default: FfiOption<SynTyKind<'ast>>,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SynTyParam<'ast> {
-    pub fn new(span: Option<SpanId>, name: SymbolId, id: GenericId) -> Self {
+    pub fn new(
+        span: Option<SpanId>,
+        name: SymbolId,
+        id: GenericId,
+        bounds: &'ast [SynTyParamBound<'ast>],
+        default: Option<SynTyKind<'ast>>,
+    ) -> Self {
         Self {
             _data: PhantomData,
             id,
             name,
             span: span.into(),
+            bounds: bounds.into(),
+            default: default.into(),
         }
     }
 }
@@ -106,6 +120,20 @@ impl<'ast> SynTyParam<'ast> {
     pub fn name(&self) -> &str {
         with_cx(self, |cx| cx.symbol_str(self.name))
     }
+
+    /// The bounds declared with this parameter, like `Copy + 'static` in
+    /// `fn foo<T: Copy + 'static>()`. Bounds declared in a `where` clause are
+    /// not included. All bounds are also available as clauses in the
+    /// [`SynGenericParams`] of the item, that introduced this parameter.
+    pub fn bounds(&self) -> &'ast [SynTyParamBound<'ast>] {
+        self.bounds.get()
+    }
+
+    /// The default type of this parameter, like `String` in
+    /// `struct Item<T = String>`.
+    pub fn default(&self) -> Option<SynTyKind<'ast>> {
+        self.default.copy()
+    }
 }
 
 impl<'ast> SynGenericParamData<'ast> for SynTyParam<'ast> {
@@ -136,16 +164,18 @@ pub struct SynLifetimeParam<'ast> {
     id: GenericId,
     name: SymbolId,
     span: FfiOption<SpanId>,
+    outlives: FfiSlice<'ast, Lifetime<'ast>>,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SynLifetimeParam<'ast> {
-    pub fn new(id: GenericId, name: SymbolId, span: Option<SpanId>) -> Self {
+    pub fn new(id: GenericId, name: SymbolId, span: Option<SpanId>, outlives: &'ast [Lifetime<'ast>]) -> Self {
         Self {
             _data: PhantomData,
             id,
             name,
             span: span.into(),
+            outlives: outlives.into(),
         }
     }
 }
@@ -158,6 +188,14 @@ impl<'ast> SynLifetimeParam<'ast> {
     pub fn name(&self) -> &str {
         with_cx(self, |cx| cx.symbol_str(self.name))
     }
+
+    /// The lifetimes, which this lifetime is declared to outlive, like `'long`
+    /// in `fn foo<'long, 'short: 'long>()`. Bounds declared in a `where` clause
+    /// are not included. All bounds are also available as clauses in the
+    /// [`SynGenericParams`] of the item, that introduced this parameter.
+    pub fn outlives(&self) -> &'ast [Lifetime<'ast>] {
+        self.outlives.get()
+    }
 }
 
 impl<'ast> SynGenericParamData<'ast> for SynLifetimeParam<'ast> {
@@ -255,11 +293,24 @@ pub enum SynWhereClauseKind<'ast> {
     Ty(&'ast SynTyClause<'ast>),
 }
 
+impl<'ast> SynWhereClauseKind<'ast> {
+    /// Returns `true`, if this clause was written in a `where` clause. Bounds
+    /// declared with a parameter, like `T: Copy` in `fn foo<T: Copy>()`, are
+    /// also represented as clauses, for which this returns `false`.
+    pub fn in_where_clause(&self) -> bool {
+        match self {
+            SynWhereClauseKind::Lifetime(clause) => clause.in_where_clause(),
+            SynWhereClauseKind::Ty(clause) => clause.in_where_clause(),
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct SynLifetimeClause<'ast> {
     lifetime: Lifetime<'ast>,
     bounds: FfiSlice<'ast, Lifetime<'ast>>,
+    in_where_clause: bool,
 }
 
 impl<'ast> SynLifetimeClause<'ast> {
@@ -270,14 +321,21 @@ impl<'ast> SynLifetimeClause<'ast> {
     pub fn bounds(&self) -> &[Lifetime<'ast>] {
         self.bounds.get()
     }
+
+    /// Returns `true`, if this clause was written in a `where` clause. See
+    /// [`SynWhereClauseKind::in_where_clause`].
+    pub fn in_where_clause(&self) -> bool {
+        self.in_where_clause
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SynLifetimeClause<'ast> {
-    pub fn new(lifetime: Lifetime<'ast>, bounds: &'ast [Lifetime<'ast>]) -> Self {
+    pub fn new(lifetime: Lifetime<'ast>, bounds: &'ast [Lifetime<'ast>], in_where_clause: bool) -> Self {
         Self {
             lifetime,
             bounds: bounds.into(),
+            in_where_clause,
         }
     }
 }
@@ -288,6 +346,7 @@ pub struct SynTyClause<'ast> {
     params: FfiOption<SynGenericParams<'ast>>,
     ty: SynTyKind<'ast>,
     bounds: FfiSlice<'ast, SynTyParamBound<'ast>>,
+    in_where_clause: bool,
 }
 
 impl<'ast> SynTyClause<'ast> {
@@ -305,6 +364,12 @@ impl<'ast> SynTyClause<'ast> {
     pub fn bounds(&self) -> &'ast [SynTyParamBound<'ast>] {
         self.bounds.get()
     }
+
+    /// Returns `true`, if this clause was written in a `where` clause. See
+    /// [`SynWhereClauseKind::in_where_clause`].
+    pub fn in_where_clause(&self) -> bool {
+        self.in_where_clause
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -313,11 +378,13 @@ impl<'ast> SynTyClause<'ast> {
         params: Option<SynGenericParams<'ast>>,
         ty: SynTyKind<'ast>,
         bounds: &'ast [SynTyParamBound<'ast>],
+        in_where_clause: bool,
     ) -> Self {
         Self {
             params: params.into(),
             ty,
             bounds: bounds.into(),
+            in_where_clause,
         }
     }
 }
@@ -1,5 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use crate::context::with_cx;
 use crate::private::Sealed;
 use crate::CtorBlocker;
 
@@ -238,19 +239,24 @@ impl<'ast> CommonItemData<'ast> {
 
 /// This struct represents the visibility of an item.
 ///
-/// Currently, it's only a placeholder until a proper representation is implemented.
-/// rust-marker/marker#26 tracks the task of implementing this. You're welcome to
-/// leave any comments in that issue.
+/// The declared visibility can be retrieved with [`Visibility::kind`].
 #[repr(C)]
 pub struct Visibility<'ast> {
     _lifetime: PhantomData<&'ast ()>,
-    _item_id: ItemId,
+    item_id: ItemId,
+}
+
+impl<'ast> Visibility<'ast> {
+    /// Returns the declared visibility of the item. This is a shorthand for
+    /// [`AstContext::item_visibility`](crate::context::AstContext::item_visibility).
+    pub fn kind(&self) -> VisibilityKind {
+        with_cx(self, |cx| cx.item_visibility(self.item_id))
+    }
 }
 
 impl<'ast> Debug for Visibility<'ast> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Visibility {{ /* WIP: See rust-marker/marker#26 */}}")
-            .finish()
+        f.debug_struct("Visibility").field("kind", &self.kind()).finish()
     }
 }
 
@@ -259,11 +265,38 @@ impl<'ast> Visibility<'ast> {
     pub fn new(item_id: ItemId) -> Self {
         Self {
             _lifetime: PhantomData,
-            _item_id: item_id,
+            item_id,
         }
     }
 }
 
+/// The declared visibility of an item, like `pub` or `pub(crate)`. This is the
+/// visibility written on the item itself. Re-exports are not considered.
+///
+/// The variant is determined by the written modifier. For example, `pub(super)`
+/// in a module directly below the crate root is [`VisibilityKind::PubSuper`],
+/// even if it has the same meaning as `pub(crate)`. `pub(self)` is
+/// [`VisibilityKind::Private`] and `pub(in crate)` is [`VisibilityKind::PubCrate`].
+///
+/// For items, where the modifier is not available, like items from other crates,
+/// the visibility is determined by the module, in which the item is visible.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VisibilityKind {
+    /// The item is public, like `pub fn foo() {}`
+    Public,
+    /// The item is visible in the current crate, like `pub(crate) fn foo() {}`
+    PubCrate,
+    /// The item is visible in the parent module, like `pub(super) fn foo() {}`
+    PubSuper,
+    /// The item is visible in the module, belonging to the given [`ItemId`],
+    /// like `pub(in crate::module) fn foo() {}`
+    PubIn(ItemId),
+    /// The item is only visible in the current module, like `fn foo() {}`
+    Private,
+}
+
 /// A body represents the expression of items.
 ///
 /// Bodies act like a barrier between the item and expression level. When items
@@ -306,7 +339,7 @@ mod test {
     fn test_item_struct_size() {
         // These sizes are allowed to change, this is just a check to have a
         // general overview and to prevent accidental changes
-        assert_eq!(56, size_of::<ModItem<'_>>(), "ModItem");
+        assert_eq!(72, size_of::<ModItem<'_>>(), "ModItem");
         assert_eq!(48, size_of::<ExternCrateItem<'_>>(), "ExternCrateItem");
         assert_eq!(64, size_of::<UseItem<'_>>(), "UseItem");
         assert_eq!(80, size_of::<StaticItem<'_>>(), "StaticItem");
@@ -1,7 +1,7 @@
 use crate::ast::expr::ConstExpr;
 use crate::ast::generic::SynGenericParams;
 use crate::ast::ty::SynTyKind;
-use crate::ast::{FieldId, Span, SpanId, SymbolId, VariantId};
+use crate::ast::{Attr, FieldId, Ident, Span, SpanId, VariantId};
 use crate::context::with_cx;
 use crate::ffi::{FfiOption, FfiSlice};
 
@@ -78,6 +78,17 @@ impl<'ast> EnumItem<'ast> {
     pub fn variants(&self) -> &[EnumVariant<'ast>] {
         self.variants.get()
     }
+
+    /// Returns the names of all variants of this enum. This is a shorthand for
+    /// iterating over [`EnumItem::variants`].
+    pub fn variant_names(&self) -> impl Iterator<Item = &Ident<'ast>> + '_ {
+        self.variants().iter().map(|variant| &variant.ident)
+    }
+
+    /// Returns the number of variants of this enum.
+    pub fn variant_count(&self) -> usize {
+        self.variants.get().len()
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -99,7 +110,7 @@ impl<'ast> EnumItem<'ast> {
 #[derive(Debug)]
 pub struct EnumVariant<'ast> {
     id: VariantId,
-    ident: SymbolId,
+    ident: Ident<'ast>,
     span: SpanId,
     kind: AdtKind<'ast>,
     discriminant: FfiOption<ConstExpr<'ast>>,
@@ -111,7 +122,7 @@ impl<'ast> EnumVariant<'ast> {
     }
 
     pub fn ident(&self) -> &str {
-        with_cx(self, |cx| cx.symbol_str(self.ident))
+        self.ident.name()
     }
 
     // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
@@ -175,7 +186,7 @@ impl<'ast> EnumVariant<'ast> {
 impl<'ast> EnumVariant<'ast> {
     pub fn new(
         id: VariantId,
-        ident: SymbolId,
+        ident: Ident<'ast>,
         span: SpanId,
         kind: AdtKind<'ast>,
         discriminant: Option<ConstExpr<'ast>>,
@@ -251,6 +262,19 @@ impl<'ast> StructItem<'ast> {
             AdtKind::Tuple(fields) | AdtKind::Field(fields) => fields.get(),
         }
     }
+
+    /// Returns the identifiers of all fields of this struct, in declaration
+    /// order. This is a shorthand for iterating over [`StructItem::fields`].
+    ///
+    /// Tuple structs use numbers as field names, these are usually not relevant
+    /// for lints checking names. This function therefore returns `None` for
+    /// tuple structs.
+    pub fn field_names(&self) -> Option<impl Iterator<Item = &Ident<'ast>> + '_> {
+        if self.is_tuple_struct() {
+            return None;
+        }
+        Some(self.fields().iter().map(|field| &field.ident))
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -287,9 +311,10 @@ impl<'ast> AdtKind<'ast> {
 pub struct Field<'ast> {
     id: FieldId,
     vis: Visibility<'ast>,
-    ident: SymbolId,
+    ident: Ident<'ast>,
     ty: SynTyKind<'ast>,
     span: SpanId,
+    attrs: FfiSlice<'ast, Attr<'ast>>,
 }
 
 impl<'ast> Field<'ast> {
@@ -303,7 +328,7 @@ impl<'ast> Field<'ast> {
     }
 
     pub fn ident(&self) -> &str {
-        with_cx(self, |cx| cx.symbol_str(self.ident))
+        self.ident.name()
     }
 
     pub fn ty(&self) -> SynTyKind<'ast> {
@@ -316,18 +341,29 @@ impl<'ast> Field<'ast> {
         with_cx(self, |cx| cx.span(self.span))
     }
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The outer attributes of this field, like `#[allow(unused)]`.
+    pub fn attrs(&self) -> &'ast [Attr<'ast>] {
+        self.attrs.get()
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> Field<'ast> {
-    pub fn new(id: FieldId, vis: Visibility<'ast>, ident: SymbolId, ty: SynTyKind<'ast>, span: SpanId) -> Self {
+    pub fn new(
+        id: FieldId,
+        vis: Visibility<'ast>,
+        ident: Ident<'ast>,
+        ty: SynTyKind<'ast>,
+        span: SpanId,
+        attrs: &'ast [Attr<'ast>],
+    ) -> Self {
         Self {
             id,
             vis,
             ident,
             ty,
             span,
+            attrs: attrs.into(),
         }
     }
 }
@@ -1,4 +1,5 @@
-use crate::ast::{ty::SynTyKind, BodyId};
+use crate::ast::{expr::ExprKind, ty::SynTyKind, BodyId};
+use crate::context::with_cx;
 use crate::ffi::FfiOption;
 
 use super::CommonItemData;
@@ -23,9 +24,18 @@ impl<'ast> ConstItem<'ast> {
         self.ty
     }
 
+    /// The [`BodyId`] of the value. It can be `None` for associated consts in
+    /// traits, which don't provide a default value.
     pub fn body_id(&self) -> Option<BodyId> {
         self.body_id.copy()
     }
+
+    /// The expression of the value, like `0xcafe` in the example above. This is
+    /// a shortcut for the expression of the [`Body`](super::Body) of
+    /// [`body_id()`](Self::body_id).
+    pub fn value(&self) -> Option<ExprKind<'ast>> {
+        self.body_id.copy().map(|id| with_cx(self, |cx| cx.body(id).expr()))
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -1,12 +1,13 @@
+use crate::ast::expr::{ClosureExpr, ExprData};
 use crate::ast::generic::SynGenericParams;
 use crate::ast::pat::PatKind;
 use crate::ast::ty::SynTyKind;
-use crate::ast::{Abi, BodyId, Constness, Safety, SpanId, Syncness};
+use crate::ast::{Abi, Attr, BodyId, Constness, Safety, SpanId, Syncness};
 use crate::context::with_cx;
 use crate::ffi::{FfiOption, FfiSlice};
 use crate::prelude::Span;
 
-use super::CommonItemData;
+use super::{CommonItemData, ItemData};
 
 /// A function item like:
 ///
@@ -73,6 +74,11 @@ impl<'ast> FnItem<'ast> {
         self.constness
     }
 
+    /// Returns `true`, if this is a `const` function.
+    pub fn is_const(&self) -> bool {
+        self.constness.is_const()
+    }
+
     /// Returns the [`Syncness`] of this callable.
     ///
     /// Use this to check if the function is async.
@@ -80,6 +86,11 @@ impl<'ast> FnItem<'ast> {
         self.syncness
     }
 
+    /// Returns `true`, if this is an `async` function.
+    pub fn is_async(&self) -> bool {
+        self.syncness.is_async()
+    }
+
     /// Returns the [`Safety`] of this callable.
     ///
     /// Use this to check if the function is unsafe.
@@ -87,6 +98,11 @@ impl<'ast> FnItem<'ast> {
         self.safety
     }
 
+    /// Returns `true`, if this is an `unsafe` function.
+    pub fn is_unsafe(&self) -> bool {
+        self.safety.is_unsafe()
+    }
+
     /// Returns `true`, if this callable is marked as `extern`. Bare functions
     /// only use the `extern` keyword to specify the ABI. These will currently
     /// still return `false` even if the keyword is present. In those cases,
@@ -154,6 +170,48 @@ impl<'ast> FnItem<'ast> {
     }
 }
 
+/// A function-like node with a body. This is used by the
+/// [`LintPass::check_fn`](crate::LintPass::check_fn) callback, to handle
+/// functions, methods and closures in one place.
+///
+/// The signature can be retrieved from the contained node. For example, the
+/// parameters are provided by [`FnItem::params`] and [`ClosureExpr::params`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum FnKind<'ast> {
+    /// A free function, an associated function or a method with a body.
+    Fn(&'ast FnItem<'ast>),
+    /// A closure expression.
+    Closure(&'ast ClosureExpr<'ast>),
+}
+
+impl<'ast> FnKind<'ast> {
+    pub fn span(&self) -> &Span<'ast> {
+        match self {
+            FnKind::Fn(item) => item.span(),
+            FnKind::Closure(expr) => expr.span(),
+        }
+    }
+
+    /// The return type, if it has been specified by the user.
+    pub fn return_ty(&self) -> Option<SynTyKind<'ast>> {
+        match self {
+            FnKind::Fn(item) => item.return_ty().copied(),
+            FnKind::Closure(expr) => expr.return_ty(),
+        }
+    }
+
+    pub fn body_id(&self) -> BodyId {
+        match self {
+            FnKind::Fn(item) => item
+                .body_id()
+                .expect("`FnKind::Fn` is only created for functions with a body"),
+            FnKind::Closure(expr) => expr.body_id(),
+        }
+    }
+}
+
 /// A parameter for a [`FnItem`], like:
 ///
 /// ```
@@ -164,12 +222,16 @@ impl<'ast> FnItem<'ast> {
 /// //                       vvvvvvvvvvvvvvvvvv
 /// fn function_with_pattern((a, b): (u32, i32)) {}
 /// ```
+///
+/// The `self` parameter of methods is represented like any other parameter,
+/// with an [`IdentPat`](crate::ast::pat::IdentPat) named `self`.
 #[repr(C)]
 #[derive(Debug)]
 pub struct FnParam<'ast> {
     span: SpanId,
     pat: PatKind<'ast>,
     ty: SynTyKind<'ast>,
+    attrs: FfiSlice<'ast, Attr<'ast>>,
 }
 
 impl<'ast> FnParam<'ast> {
@@ -184,11 +246,26 @@ impl<'ast> FnParam<'ast> {
     pub fn ty(&self) -> SynTyKind<'ast> {
         self.ty
     }
+
+    /// The outer attributes of this parameter, like `#[allow(unused)]` in
+    /// `fn foo(#[allow(unused)] a: u32)`.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will return an empty slice for parameters of functions without a body, like
+    ///   required trait functions.
+    pub fn attrs(&self) -> &'ast [Attr<'ast>] {
+        self.attrs.get()
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> FnParam<'ast> {
-    pub fn new(span: SpanId, pat: PatKind<'ast>, ty: SynTyKind<'ast>) -> Self {
-        Self { span, pat, ty }
+    pub fn new(span: SpanId, pat: PatKind<'ast>, ty: SynTyKind<'ast>, attrs: &'ast [Attr<'ast>]) -> Self {
+        Self {
+            span,
+            pat,
+            ty,
+            attrs: attrs.into(),
+        }
     }
 }
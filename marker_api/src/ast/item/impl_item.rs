@@ -45,14 +45,17 @@ pub struct ImplItem<'ast> {
 super::impl_item_data!(ImplItem, Impl);
 
 impl<'ast> ImplItem<'ast> {
+    /// Returns `true`, if this is an `unsafe impl`.
     pub fn is_unsafe(&self) -> bool {
         self.is_unsafe
     }
 
+    /// The generic parameters and where clauses declared on this impl block.
     pub fn generics(&self) -> &SynGenericParams<'ast> {
         &self.generics
     }
 
+    /// Returns `true`, if this is a negative trait impl, like `impl !Send for T {}`.
     pub fn is_negated(&self) -> bool {
         self.is_negated
     }
@@ -61,15 +64,19 @@ impl<'ast> ImplItem<'ast> {
         matches!(self.trait_ref, FfiOption::Some(..))
     }
 
+    /// The trait that is implemented by this impl block, or `None` for inherent
+    /// impls.
     pub fn trait_ref(&self) -> Option<&TraitRef<'ast>> {
         self.trait_ref.get()
     }
 
+    /// The associated items defined in this impl block.
     pub fn items(&self) -> &[AssocItemKind<'ast>] {
         self.items.get()
     }
 
-    pub fn ty(&self) -> SynTyKind {
+    /// The self type, which this impl block is defined for.
+    pub fn ty(&self) -> SynTyKind<'ast> {
         self.ty
     }
 }
@@ -1,4 +1,8 @@
-use crate::ffi::FfiSlice;
+use crate::{
+    ast::{Span, SpanId},
+    context::with_cx,
+    ffi::FfiSlice,
+};
 
 use super::{CommonItemData, ItemKind};
 
@@ -13,12 +17,18 @@ use super::{CommonItemData, ItemKind};
 /// }
 /// ```
 ///
+/// Modules can also be loaded from a separate file, like `mod module;`. The
+/// items of these modules are available in the same way as for inline modules.
+/// [`ModItem::is_inline`] can be used to distinguish the two.
+///
 /// * See <https://doc.rust-lang.org/stable/reference/items/modules.html>
 #[repr(C)]
 #[derive(Debug)]
 pub struct ModItem<'ast> {
     data: CommonItemData<'ast>,
     items: FfiSlice<'ast, ItemKind<'ast>>,
+    is_inline: bool,
+    inner_span: SpanId,
 }
 
 super::impl_item_data!(ModItem, Mod);
@@ -27,14 +37,40 @@ impl<'ast> ModItem<'ast> {
     pub fn items(&self) -> &[ItemKind<'ast>] {
         self.items.get()
     }
+
+    /// Returns `true`, if the items of this module are defined inline, like
+    /// `mod module { ... }`. Modules loaded from a separate file, like
+    /// `mod module;`, will return `false`.
+    pub fn is_inline(&self) -> bool {
+        self.is_inline
+    }
+
+    /// Returns the path of the file, that contains the items of this module.
+    /// `None` is returned for inline modules. The path is relative to the root
+    /// of the crate, like [`Span::file_name`].
+    pub fn file_path(&self) -> Option<&str> {
+        if self.is_inline {
+            return None;
+        }
+        with_cx(self, |cx| cx.span(self.inner_span)).file_name()
+    }
+
+    /// Returns the span of the module content. For inline modules, this is the
+    /// span between the curly braces. For modules loaded from a file, this
+    /// span covers the content of the file.
+    pub fn inner_span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.inner_span))
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> ModItem<'ast> {
-    pub fn new(data: CommonItemData<'ast>, items: &'ast [ItemKind<'ast>]) -> Self {
+    pub fn new(data: CommonItemData<'ast>, items: &'ast [ItemKind<'ast>], is_inline: bool, inner_span: SpanId) -> Self {
         Self {
             data,
             items: items.into(),
+            is_inline,
+            inner_span,
         }
     }
 }
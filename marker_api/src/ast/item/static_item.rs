@@ -1,5 +1,6 @@
 use crate::{
-    ast::{ty::SynTyKind, BodyId, Mutability},
+    ast::{expr::ExprKind, ty::SynTyKind, BodyId, Mutability},
+    context::with_cx,
     ffi::FfiOption,
 };
 
@@ -13,8 +14,12 @@ use super::CommonItemData;
 /// // `mutability()` -> `Mutability::Mut`
 /// // `ty()` -> _Ty of u32_
 /// // `body_id()` -> _BodyId of `0`_
+/// // `value()` -> _Expr of `0`_
 /// ```
 ///
+/// Static items declared inside `extern` blocks are also represented by this
+/// node. These items have no initializer, see [`StaticItem::is_extern`].
+///
 /// See: <https://doc.rust-lang.org/stable/reference/items/static-items.html>
 #[repr(C)]
 #[derive(Debug)]
@@ -38,10 +43,23 @@ impl<'ast> StaticItem<'ast> {
         self.ty
     }
 
-    /// This returns the [`BodyId`] of the initialization body.
+    /// This returns the [`BodyId`] of the initialization body. `None` is
+    /// returned for static items declared in `extern` blocks.
     pub fn body_id(&self) -> Option<BodyId> {
         self.body_id.copy()
     }
+
+    /// The expression used to initialize this static item. This is a shortcut
+    /// for the expression of the body returned by [`StaticItem::body_id`].
+    pub fn value(&self) -> Option<ExprKind<'ast>> {
+        self.body_id.copy().map(|id| with_cx(self, |cx| cx.body(id).expr()))
+    }
+
+    /// Returns `true`, if this static item is declared inside an `extern` block
+    /// and therefore has no initializer.
+    pub fn is_extern(&self) -> bool {
+        self.body_id.is_none()
+    }
 }
 
 #[cfg(feature = "driver-api")]
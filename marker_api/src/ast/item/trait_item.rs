@@ -29,10 +29,12 @@ pub struct TraitItem<'ast> {
 super::impl_item_data!(TraitItem, Trait);
 
 impl<'ast> TraitItem<'ast> {
+    /// Returns `true`, if this is an `unsafe trait`.
     pub fn is_unsafe(&self) -> bool {
         self.is_unsafe
     }
 
+    /// The generic parameters and where clauses declared on this trait.
     pub fn generics(&self) -> &SynGenericParams<'ast> {
         &self.generics
     }
@@ -50,6 +52,9 @@ impl<'ast> TraitItem<'ast> {
         self.supertraits.get()
     }
 
+    /// The associated items declared in this trait. Required and provided
+    /// functions are both represented as [`AssocItemKind::Fn`]. They can be
+    /// distinguished with [`FnItem::body_id`](super::FnItem::body_id).
     pub fn items(&self) -> &[AssocItemKind<'ast>] {
         self.items.get()
     }
@@ -39,7 +39,10 @@ impl<'ast> TyAliasItem<'ast> {
         self.bounds.get()
     }
 
-    pub fn aliased_ty(&self) -> Option<SynTyKind> {
+    /// The aliased type, like `(T, T, T)` in the example above. For type
+    /// aliases in [`TraitItem`](`super::TraitItem`)s, this is the default type,
+    /// if one was specified.
+    pub fn aliased_ty(&self) -> Option<SynTyKind<'ast>> {
         self.aliased_ty.copy()
     }
 }
@@ -19,6 +19,10 @@ use super::CommonItemData;
 /// // `use_kind()` -> `Single`
 /// ```
 ///
+/// Nested `use` trees are desugared into individual `use` items. For example,
+/// `use foo::{bar, baz::*}` is represented as two items, one for `foo::bar`
+/// and one for the glob import `foo::baz::*`.
+///
 /// See <https://doc.rust-lang.org/stable/reference/items/use-declarations.html>
 #[repr(C)]
 #[derive(Debug)]
@@ -49,6 +53,7 @@ impl<'ast> UseItem<'ast> {
         &self.use_path
     }
 
+    /// Returns `true`, if this is a glob import like `use foo::*`.
     pub fn is_glob(&self) -> bool {
         matches!(self.use_kind, UseKind::Glob)
     }
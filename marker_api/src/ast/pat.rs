@@ -37,6 +37,12 @@ pub use wildcard_pat::*;
 pub trait PatData<'ast>: Debug + Sealed {
     /// Returns the [`Span`] of this pattern.
     fn span(&self) -> &Span<'ast>;
+
+    /// Returns this pattern wrapped in it's [`PatKind`] variant.
+    ///
+    /// In function parameters, it's recommended to use `Into<PatKind<'ast>>`
+    /// as a bound to support all patterns and `PatKind<'ast>` as parameters.
+    fn as_pat(&'ast self) -> PatKind<'ast>;
 }
 
 #[repr(C)]
@@ -115,6 +121,10 @@ impl<'ast> PatData<'ast> for ExprKind<'ast> {
     fn span(&self) -> &Span<'ast> {
         self.span()
     }
+
+    fn as_pat(&'ast self) -> PatKind<'ast> {
+        PatKind::Place(*self, CtorBlocker::new())
+    }
 }
 
 #[repr(C)]
@@ -146,6 +156,10 @@ macro_rules! impl_pat_data {
             fn span(&self) -> &crate::ast::Span<'ast> {
                 $crate::context::with_cx(self, |cx| cx.span(self.data.span))
             }
+
+            fn as_pat(&'ast self) -> $crate::ast::pat::PatKind<'ast> {
+                $crate::ast::pat::PatKind::$enum_name(self)
+            }
         }
 
         impl<'ast> From<&'ast $self_ty> for $crate::ast::pat::PatKind<'ast> {
@@ -34,6 +34,27 @@ impl<'ast> IdentPat<'ast> {
         self.is_ref
     }
 
+    /// Returns the [`BindingMode`] of this variable. The mutability of bindings
+    /// by value is available via [`IdentPat::mutability`].
+    ///
+    /// ```
+    /// # let (a, b, c, d) = (1, 2, 3, 4);
+    /// let (a, mut b, ref c, ref mut d) = (a, b, c, d);
+    /// //   ^  ^^^^^  ^^^^^  ^^^^^^^^^
+    /// //   |  |      |      +-- `BindingMode::Ref(Mutability::Mut)`
+    /// //   |  |      +--------- `BindingMode::Ref(Mutability::Unmut)`
+    /// //   |  +---------------- `BindingMode::Value`
+    /// //   +------------------- `BindingMode::Value`
+    /// # let _ = (a, b, c, d);
+    /// ```
+    pub fn binding_mode(&self) -> BindingMode {
+        if self.is_ref {
+            BindingMode::Ref(self.mutability)
+        } else {
+            BindingMode::Value
+        }
+    }
+
     /// The pattern, if the variable originates from a binding to a pattern.
     /// ```
     /// # let expr = 10;
@@ -46,11 +67,27 @@ impl<'ast> IdentPat<'ast> {
     ///     _ => println!("x is most likely negative"),
     /// }
     /// ```
+    pub fn subpattern(&self) -> Option<PatKind<'ast>> {
+        self.binding_pat.copy()
+    }
+
+    /// This is an alias for [`IdentPat::subpattern`].
     pub fn binding_pat(&self) -> Option<PatKind<'ast>> {
         self.binding_pat.copy()
     }
 }
 
+/// The binding mode of an [`IdentPat`], indicating how the value is bound to
+/// the variable.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BindingMode {
+    /// The value is moved or copied into the variable, like `x` or `mut x`.
+    Value,
+    /// The variable is a reference to the value, like `ref x` or `ref mut x`.
+    Ref(Mutability),
+}
+
 super::impl_pat_data!(IdentPat<'ast>, Ident);
 
 #[cfg(feature = "driver-api")]
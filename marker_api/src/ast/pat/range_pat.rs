@@ -1,15 +1,19 @@
-use crate::{ast::expr::ExprKind, ffi::FfiOption};
+use crate::{
+    ast::expr::{ExprKind, LitExprKind},
+    ffi::FfiOption,
+};
 
 use super::CommonPatData;
 
-/// A range expression, like these:
+/// A range pattern, like these:
 ///
 /// ```
-/// 1..9;
-/// 3..;
-/// ..5;
-/// ..;
-/// 0..=1;
+/// # let x = 4;
+/// match x {
+///     1..=9 => {},
+///     10.. => {},
+///     i32::MIN..=0 => {},
+/// }
 /// ```
 #[repr(C)]
 #[derive(Debug)]
@@ -21,14 +25,32 @@ pub struct RangePat<'ast> {
 }
 
 impl<'ast> RangePat<'ast> {
+    /// Returns the start of the range, if one was specified. This can either be
+    /// a literal or a path to a constant, like `i32::MIN`. Literals can be
+    /// accessed more conveniently via [`RangePat::start_lit`].
     pub fn start(&self) -> Option<ExprKind<'ast>> {
         self.start.copy()
     }
 
+    /// Returns the end of the range, if one was specified. This can either be
+    /// a literal or a path to a constant, like `i32::MAX`. Literals can be
+    /// accessed more conveniently via [`RangePat::end_lit`].
     pub fn end(&self) -> Option<ExprKind<'ast>> {
         self.end.copy()
     }
 
+    /// Returns the start of the range, if it's a literal. `None` is returned,
+    /// if the range has no start or if it's a path to a constant.
+    pub fn start_lit(&self) -> Option<LitExprKind<'ast>> {
+        self.start().and_then(|expr| expr.try_into().ok())
+    }
+
+    /// Returns the end of the range, if it's a literal. `None` is returned,
+    /// if the range has no end or if it's a path to a constant.
+    pub fn end_lit(&self) -> Option<LitExprKind<'ast>> {
+        self.end().and_then(|expr| expr.try_into().ok())
+    }
+
     pub fn is_inclusive(&self) -> bool {
         self.is_inclusive
     }
@@ -210,9 +210,16 @@ macro_rules! impl_ty_data {
 use impl_ty_data;
 
 /// The semantic representation of a type.
+///
+/// Semantic types can be compared with `==`. The comparison is purely
+/// structural, types are not normalized and no unification is performed. Two
+/// generic types are therefore only equal, if they refer to the same generic
+/// parameter. Constants, like const generic arguments, are compared by their
+/// evaluated [`ConstValue`](crate::ast::ConstValue). Unstable types are never
+/// equal, see [`SemUnstableTy`].
 #[repr(C)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum SemTyKind<'ast> {
     // ================================
     // Primitive types
@@ -257,6 +264,9 @@ pub enum SemTyKind<'ast> {
     // ================================
     /// A trait object like [`dyn Trait`](https://doc.rust-lang.org/stable/std/keyword.dyn.html)
     TraitObj(&'ast SemTraitObjTy<'ast>),
+    /// An opaque type like [`impl Trait`](https://doc.rust-lang.org/stable/std/keyword.impl.html)
+    /// in return position.
+    ImplTrait(&'ast SemImplTraitTy<'ast>),
     // ================================
     // User defined types
     // ================================
@@ -275,3 +285,11 @@ pub enum SemTyKind<'ast> {
     /// and therefor not represented as part of the API.
     Unstable(&'ast SemUnstableTy<'ast>),
 }
+
+impl<'ast> SemTyKind<'ast> {
+    /// Returns `true` if this is the never type [`!`](prim@never).
+    #[must_use]
+    pub fn is_never(&self) -> bool {
+        matches!(self, Self::Never(..))
+    }
+}
@@ -5,7 +5,7 @@ use super::CommonSynTyData;
 /// A [function item type](https://doc.rust-lang.org/reference/types/function-item.html)
 /// identifying a specific function and potentualy additional generics.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemFnTy<'ast> {
     fn_id: ItemId,
     generics: SemGenericArgs<'ast>,
@@ -56,7 +56,7 @@ impl_callable_data_trait!(SynClosureTy<'ast>);
 /// Closure expressions create anonymous types, which implement traits to call the
 /// closure. This type on it's own therefore only identifies the type of the closure.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemClosureTy<'ast> {
     closure_ty_id: TyDefId,
     generics: SemGenericArgs<'ast>,
@@ -4,12 +4,21 @@ use super::CommonSynTyData;
 
 /// The placeholder type, signalling that the semantic type is still unstable
 /// and therefor not represented as part of the API.
+///
+/// Unstable types are never equal to any other type, not even to themselves,
+/// as the actual types they represent are unknown.
 #[repr(C)]
 #[derive(Debug)]
 pub struct SemUnstableTy<'ast> {
     _lt: PhantomData<&'ast ()>,
 }
 
+impl<'ast> PartialEq for SemUnstableTy<'ast> {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
 #[cfg(feature = "driver-api")]
 impl<'ast> SemUnstableTy<'ast> {
     pub fn new() -> Self {
@@ -26,7 +26,7 @@ impl<'ast> std::fmt::Display for SynBoolTy<'ast> {
 
 /// The semantic representation of the [`bool`] type.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct SemBoolTy<'ast> {
     _lt: PhantomData<&'ast ()>,
 }
@@ -90,7 +90,7 @@ impl<'ast> std::fmt::Display for SynNumTy<'ast> {
 
 /// The semantic representation of a numeric type like [`u32`], [`i32`], [`f64`].
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct SemNumTy<'ast> {
     _ast: PhantomData<&'ast ()>,
     numeric_kind: NumKind,
@@ -242,6 +242,7 @@ impl<'ast> SynTextTy<'ast> {
 
 /// The semantic representation of a textual type like [`char`] or [`str`].
 #[repr(C)]
+#[derive(PartialEq, Eq)]
 pub struct SemTextTy<'ast> {
     _ast: PhantomData<&'ast ()>,
     textual_kind: TextKind,
@@ -317,6 +318,7 @@ impl<'ast> SynNeverTy<'ast> {
 
 /// The semantic representation of the never type [`!`](prim@never).
 #[repr(C)]
+#[derive(PartialEq, Eq)]
 pub struct SemNeverTy<'ast> {
     _lt: PhantomData<&'ast ()>,
 }
@@ -57,7 +57,7 @@ impl<'ast> SynRefTy<'ast> {
 /// Marker currently doesn't support the analysis of lifetimes. Removing them
 /// from the type also simplifies type comparisons.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemRefTy<'ast> {
     mutability: Mutability,
     inner_ty: SemTyKind<'ast>,
@@ -118,7 +118,7 @@ impl<'ast> SynRawPtrTy<'ast> {
 /// The semantic representation of a raw pointer like [`*const T`](prim@pointer)
 /// or [`*mut T`](prim@pointer)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemRawPtrTy<'ast> {
     mutability: Mutability,
     inner_ty: SemTyKind<'ast>,
@@ -162,7 +162,7 @@ impl_callable_data_trait!(SynFnPtrTy<'ast>);
 
 /// The semantic representation of a function pointer, like [`fn (T) -> U`](prim@fn)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemFnPtrTy<'ast> {
     safety: Safety,
     abi: Abi,
@@ -175,6 +175,11 @@ impl<'ast> SemFnPtrTy<'ast> {
         self.safety
     }
 
+    /// Returns `true`, if this is an `unsafe` function pointer.
+    pub fn is_unsafe(&self) -> bool {
+        self.safety.is_unsafe()
+    }
+
     pub fn abi(&self) -> Abi {
         self.abi
     }
@@ -45,7 +45,7 @@ impl<'ast> std::fmt::Display for SynTupleTy<'ast> {
 
 /// The semantic representation of a tuple type like [`()`](prim@tuple) or [`(T, U)`](prim@tuple)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemTupleTy<'ast> {
     types: FfiSlice<'ast, SemTyKind<'ast>>,
 }
@@ -54,6 +54,17 @@ impl<'ast> SemTupleTy<'ast> {
     pub fn types(&self) -> &[SemTyKind<'ast>] {
         self.types.as_slice()
     }
+
+    /// Returns the number of types in this tuple.
+    pub fn len(&self) -> usize {
+        self.types.as_slice().len()
+    }
+
+    /// Returns `true`, if this is the unit type [`()`](prim@unit). The unit
+    /// type is represented as a tuple without any types.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -106,6 +117,7 @@ impl<'ast> std::fmt::Display for SynSliceTy<'ast> {
 
 /// The semantic representation of a variable length slice like [`[T]`](prim@slice)
 #[repr(C)]
+#[derive(PartialEq)]
 pub struct SemSliceTy<'ast> {
     inner_ty: SemTyKind<'ast>,
 }
@@ -172,7 +184,7 @@ impl<'ast> std::fmt::Display for SynArrayTy<'ast> {
 
 /// The semantic representation of an array with a known size like: [`[T; N]`](prim@array)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemArrayTy<'ast> {
     inner_ty: SemTyKind<'ast>,
     len: ConstValue<'ast>,
@@ -183,6 +195,10 @@ impl<'ast> SemArrayTy<'ast> {
         self.inner_ty
     }
 
+    /// Returns the evaluated length of this array. [`ConstValue::as_u64`] can
+    /// be used to get the length as a number. The value is
+    /// [`ConstValue::Opaque`], if the length can't be evaluated, for example,
+    /// if it depends on a generic constant like `N` in `[T; N]`.
     pub fn len(&self) -> &ConstValue<'ast> {
         &self.len
     }
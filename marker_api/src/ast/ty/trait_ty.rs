@@ -30,6 +30,33 @@ impl<'ast> SynImplTraitTy<'ast> {
     }
 }
 
+/// The semantic representation of an [`impl Trait`] type in return position,
+/// also called an opaque type. The concrete type is hidden behind the trait
+/// bounds. An `impl Trait` type in argument position is represented as a
+/// [`SemTyKind::Generic`](super::SemTyKind::Generic) type.
+///
+/// [`impl Trait`]: https://doc.rust-lang.org/reference/types/impl-trait.html
+#[repr(C)]
+#[derive(Debug, PartialEq)]
+pub struct SemImplTraitTy<'ast> {
+    bound: FfiSlice<'ast, SemTraitBound<'ast>>,
+}
+
+impl<'ast> SemImplTraitTy<'ast> {
+    /// Returns the trait bounds of this type. This includes implicit bounds,
+    /// like `Sized`, which are added by the compiler.
+    pub fn bounds(&self) -> &[SemTraitBound<'ast>] {
+        self.bound.get()
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> SemImplTraitTy<'ast> {
+    pub fn new(bound: &'ast [SemTraitBound<'ast>]) -> Self {
+        Self { bound: bound.into() }
+    }
+}
+
 /// The syntactic representation of a [trait object].
 ///
 /// [trait object]: https://doc.rust-lang.org/reference/types/trait-object.html
@@ -62,20 +89,48 @@ impl<'ast> SynTraitObjTy<'ast> {
 ///
 /// [trait object]: https://doc.rust-lang.org/reference/types/trait-object.html
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemTraitObjTy<'ast> {
+    /// The principal trait, if present, is always stored as the first bound.
+    /// All other bounds are auto traits.
     bound: FfiSlice<'ast, SemTraitBound<'ast>>,
+    has_principal: bool,
 }
 
 impl<'ast> SemTraitObjTy<'ast> {
+    /// Returns all trait bounds of this trait object, this includes the
+    /// [principal](SemTraitObjTy::principal) trait and
+    /// [auto traits](SemTraitObjTy::auto_traits).
     pub fn bounds(&self) -> &[SemTraitBound<'ast>] {
         self.bound.get()
     }
+
+    /// Returns the main trait of this trait object, like `Iterator` in
+    /// `dyn Iterator<Item = u8> + Send`. Trait objects can consist of only
+    /// auto traits, like `dyn Send + Sync`. In those cases `None` is returned.
+    pub fn principal(&self) -> Option<&SemTraitBound<'ast>> {
+        if self.has_principal {
+            self.bounds().first()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [auto traits] of this trait object, like `Send` and `Sync`
+    /// in `dyn Debug + Send + Sync`.
+    ///
+    /// [auto traits]: https://doc.rust-lang.org/reference/special-types-and-traits.html#auto-traits
+    pub fn auto_traits(&self) -> &[SemTraitBound<'ast>] {
+        &self.bounds()[usize::from(self.has_principal)..]
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> SemTraitObjTy<'ast> {
-    pub fn new(bound: &'ast [SemTraitBound<'ast>]) -> Self {
-        Self { bound: bound.into() }
+    pub fn new(bound: &'ast [SemTraitBound<'ast>], has_principal: bool) -> Self {
+        Self {
+            bound: bound.into(),
+            has_principal,
+        }
     }
 }
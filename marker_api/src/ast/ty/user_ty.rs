@@ -1,8 +1,11 @@
 use std::marker::PhantomData;
 
-use crate::ast::{generic::SemGenericArgs, AstQPath, GenericId, ItemId, TyDefId};
+use crate::{
+    ast::{generic::SemGenericArgs, AstQPath, GenericId, ItemId, TyDefId},
+    context::with_cx,
+};
 
-use super::CommonSynTyData;
+use super::{CommonSynTyData, SemTyKind};
 
 /// A type identified via a [`AstQPath`]. The kind and definition can be
 /// accessed via the ID returned by [`AstQPath::resolve()`].
@@ -42,7 +45,7 @@ impl<'ast> SynPathTy<'ast> {
 /// [`Enum`]: https://doc.rust-lang.org/reference/types/enum.html
 /// [`Union`]: https://doc.rust-lang.org/reference/types/union.html
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SemAdtTy<'ast> {
     def_id: TyDefId,
     generics: SemGenericArgs<'ast>,
@@ -58,6 +61,18 @@ impl<'ast> SemAdtTy<'ast> {
     pub fn generics(&self) -> &SemGenericArgs<'ast> {
         &self.generics
     }
+
+    /// This returns the type of the field with the given name, with the generic
+    /// arguments of this type applied. Fields of tuple structs are named by
+    /// their index, like `"0"`. `None` is returned, if no field with the name
+    /// exists or if this type is an enum, as enum fields belong to a variant.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will return `None` for types which can't be fully represented by semantic
+    ///   types, like types with const generic arguments.
+    pub fn field_ty(&'ast self, name: &str) -> Option<SemTyKind<'ast>> {
+        with_cx(self, |cx| cx.field_ty_of(self, name))
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -76,7 +91,7 @@ impl<'ast> SemAdtTy<'ast> {
 /// }
 /// ```
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct SemGenericTy<'ast> {
     _lifetime: PhantomData<&'ast ()>,
     generic_id: GenericId,
@@ -107,7 +122,7 @@ impl<'ast> SemGenericTy<'ast> {
 /// kind, is primarily used for instances, where the concrete aliased type is not yet
 /// known.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct SemAliasTy<'ast> {
     _lifetime: PhantomData<&'ast ()>,
     alias_item: ItemId,
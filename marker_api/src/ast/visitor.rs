@@ -0,0 +1,439 @@
+//! A visitor to traverse the AST, for lint crates, which need to do their own
+//! nested traversals.
+//!
+//! The [`Visitor`] trait has a callback for every node kind. The `walk_*`
+//! functions call these callbacks for the given node and all its sub nodes.
+//! The traversal stops, once a callback returns [`ControlFlow::Break`].
+
+use std::ops::ControlFlow;
+
+use crate::{
+    ast::{
+        expr::{ExprKind, RangeKind},
+        generic::{SynGenericParamKind, SynGenericParams},
+        item::{Body, EnumVariant, Field, ItemKind},
+        pat::PatKind,
+        stmt::StmtKind,
+    },
+    context::AstContext,
+};
+
+pub trait Visitor<'ast, B> {
+    fn visit_item(&mut self, _cx: &'ast AstContext<'ast>, _item: ItemKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_field(&mut self, _cx: &'ast AstContext<'ast>, _field: &'ast Field<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_variant(&mut self, _cx: &'ast AstContext<'ast>, _variant: &'ast EnumVariant<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_generic_param(
+        &mut self,
+        _cx: &'ast AstContext<'ast>,
+        _param: &'ast SynGenericParamKind<'ast>,
+    ) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_body(&mut self, _cx: &'ast AstContext<'ast>, _body: &'ast Body<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_stmt(&mut self, _cx: &'ast AstContext<'ast>, _stmt: StmtKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_expr(&mut self, _cx: &'ast AstContext<'ast>, _expr: ExprKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    /// This is called after all sub nodes of the expression have been visited.
+    /// It can be used to traverse expressions in post-order.
+    fn visit_expr_post(&mut self, _cx: &'ast AstContext<'ast>, _expr: ExprKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_pat(&mut self, _cx: &'ast AstContext<'ast>, _pat: PatKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+}
+
+pub fn walk_item<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<'ast, B>,
+    kind: ItemKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_item(cx, kind)?;
+    match kind {
+        ItemKind::Mod(module) => {
+            for mod_item in module.items() {
+                walk_item(cx, visitor, *mod_item)?;
+            }
+        },
+        ItemKind::Static(item) => {
+            if let Some(body_id) = item.body_id() {
+                walk_body(cx, visitor, cx.body(body_id))?;
+            }
+        },
+        ItemKind::Const(item) => {
+            if let Some(body_id) = item.body_id() {
+                walk_body(cx, visitor, cx.body(body_id))?;
+            }
+        },
+        ItemKind::Fn(item) => {
+            walk_generic_params(cx, visitor, item.generics())?;
+            for param in item.params() {
+                walk_pat(cx, visitor, param.pat())?;
+            }
+            if let Some(body_id) = item.body_id() {
+                walk_body(cx, visitor, cx.body(body_id))?;
+            }
+        },
+        ItemKind::Struct(item) => {
+            walk_generic_params(cx, visitor, item.generics())?;
+            for field in item.fields() {
+                visitor.visit_field(cx, field)?;
+            }
+        },
+        ItemKind::Union(item) => {
+            walk_generic_params(cx, visitor, item.generics())?;
+            for field in item.fields() {
+                visitor.visit_field(cx, field)?;
+            }
+        },
+        ItemKind::Enum(item) => {
+            walk_generic_params(cx, visitor, item.generics())?;
+            for variant in item.variants() {
+                visitor.visit_variant(cx, variant)?;
+            }
+        },
+        ItemKind::Trait(item) => {
+            walk_generic_params(cx, visitor, item.generics())?;
+            for assoc_item in item.items() {
+                walk_item(cx, visitor, assoc_item.as_item())?;
+            }
+        },
+        ItemKind::Impl(item) => {
+            walk_generic_params(cx, visitor, item.generics())?;
+            for assoc_item in item.items() {
+                walk_item(cx, visitor, assoc_item.as_item())?;
+            }
+        },
+        ItemKind::ExternBlock(item) => {
+            for ext_item in item.items() {
+                walk_item(cx, visitor, ext_item.as_item())?;
+            }
+        },
+        ItemKind::TyAlias(item) => {
+            walk_generic_params(cx, visitor, item.generics())?;
+        },
+        ItemKind::ExternCrate(_) | ItemKind::Use(_) | ItemKind::Unstable(_) => {
+            // These items have no sub nodes, which are visited by this visitor
+        },
+    }
+    ControlFlow::Continue(())
+}
+
+pub fn walk_generic_params<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<'ast, B>,
+    generics: &'ast SynGenericParams<'ast>,
+) -> ControlFlow<B> {
+    for param in generics.params() {
+        visitor.visit_generic_param(cx, param)?;
+    }
+
+    ControlFlow::Continue(())
+}
+
+pub fn walk_body<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<'ast, B>,
+    body: &'ast Body<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_body(cx, body)?;
+
+    walk_expr(cx, visitor, body.expr())?;
+
+    ControlFlow::Continue(())
+}
+
+pub fn walk_stmt<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<'ast, B>,
+    stmt: StmtKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_stmt(cx, stmt)?;
+
+    match stmt {
+        StmtKind::Item(item, _) => {
+            walk_item(cx, visitor, *item)?;
+        },
+        StmtKind::Let(lt) => {
+            walk_pat(cx, visitor, lt.pat())?;
+            if let Some(init) = lt.init() {
+                walk_expr(cx, visitor, init)?;
+            }
+            if let Some(els) = lt.els() {
+                walk_expr(cx, visitor, els)?;
+            }
+        },
+        StmtKind::Expr(expr, _) => {
+            walk_expr(cx, visitor, *expr)?;
+        },
+    }
+
+    ControlFlow::Continue(())
+}
+
+#[allow(clippy::too_many_lines)]
+pub fn walk_expr<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<'ast, B>,
+    expr: ExprKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_expr(cx, expr)?;
+
+    match expr {
+        ExprKind::Block(e) => {
+            for stmt in e.stmts() {
+                walk_stmt(cx, visitor, *stmt)?;
+            }
+            if let Some(block_expr) = e.expr() {
+                walk_expr(cx, visitor, block_expr)?;
+            }
+        },
+        ExprKind::Closure(e) => {
+            for param in e.params() {
+                walk_pat(cx, visitor, param.pat())?;
+            }
+            walk_body(cx, visitor, cx.body(e.body_id()))?;
+        },
+        ExprKind::UnaryOp(e) => {
+            walk_expr(cx, visitor, e.expr())?;
+        },
+        ExprKind::Ref(e) => {
+            walk_expr(cx, visitor, e.expr())?;
+        },
+        ExprKind::BinaryOp(e) => {
+            walk_expr(cx, visitor, e.left())?;
+            walk_expr(cx, visitor, e.right())?;
+        },
+        ExprKind::QuestionMark(e) => {
+            walk_expr(cx, visitor, e.expr())?;
+        },
+        ExprKind::Assign(e) => {
+            walk_pat(cx, visitor, e.assignee())?;
+            walk_expr(cx, visitor, e.value())?;
+        },
+        ExprKind::As(e) => {
+            walk_expr(cx, visitor, e.expr())?;
+        },
+        ExprKind::Call(e) => {
+            walk_expr(cx, visitor, e.operand())?;
+            for arg in e.args() {
+                walk_expr(cx, visitor, *arg)?;
+            }
+        },
+        ExprKind::Method(e) => {
+            walk_expr(cx, visitor, e.receiver())?;
+            for arg in e.args() {
+                walk_expr(cx, visitor, *arg)?;
+            }
+        },
+        ExprKind::Array(e) => {
+            for el in e.elements() {
+                walk_expr(cx, visitor, *el)?;
+            }
+            if let Some(len) = e.len() {
+                walk_expr(cx, visitor, len.expr())?;
+            }
+        },
+        ExprKind::Tuple(e) => {
+            for el in e.elements() {
+                walk_expr(cx, visitor, *el)?;
+            }
+        },
+        ExprKind::Ctor(e) => {
+            for field in e.fields() {
+                walk_expr(cx, visitor, field.expr())?;
+            }
+            if let Some(base) = e.base() {
+                walk_expr(cx, visitor, base)?;
+            }
+        },
+        // I like the simplicity of the API, even if the dereference part of
+        // slices is a bit annoying. But typing all of this out is kind of meh.
+        // not super interesting and almost just copy pasta, but not enough for
+        // a macro... Oh well, back to work
+        ExprKind::Range(e) => match e.kind() {
+            RangeKind::HalfOpen { start, end } | RangeKind::Closed { start, end } => {
+                walk_expr(cx, visitor, start)?;
+                walk_expr(cx, visitor, end)?;
+            },
+            RangeKind::From { start } => {
+                walk_expr(cx, visitor, start)?;
+            },
+            RangeKind::To { end } | RangeKind::ToInclusive { end } => {
+                walk_expr(cx, visitor, end)?;
+            },
+            RangeKind::Full => {},
+        },
+        ExprKind::Index(e) => {
+            walk_expr(cx, visitor, e.operand())?;
+            walk_expr(cx, visitor, e.index())?;
+        },
+        ExprKind::Field(e) => {
+            walk_expr(cx, visitor, e.operand())?;
+        },
+        ExprKind::If(e) => {
+            walk_expr(cx, visitor, e.condition())?;
+            walk_expr(cx, visitor, e.then())?;
+            if let Some(els) = e.els() {
+                walk_expr(cx, visitor, els)?;
+            }
+        },
+        ExprKind::Let(e) => {
+            walk_pat(cx, visitor, e.pat())?;
+            walk_expr(cx, visitor, e.scrutinee())?;
+        },
+        ExprKind::Match(e) => {
+            walk_expr(cx, visitor, e.scrutinee())?;
+            for arm in e.arms() {
+                walk_pat(cx, visitor, arm.pat())?;
+                if let Some(guard) = arm.guard() {
+                    walk_expr(cx, visitor, guard)?;
+                }
+                walk_expr(cx, visitor, arm.expr())?;
+            }
+        },
+        ExprKind::Break(e) => {
+            if let Some(val) = e.expr() {
+                walk_expr(cx, visitor, val)?;
+            }
+        },
+        ExprKind::Return(e) => {
+            if let Some(val) = e.expr() {
+                walk_expr(cx, visitor, val)?;
+            }
+        },
+        ExprKind::For(e) => {
+            walk_pat(cx, visitor, e.pat())?;
+            walk_expr(cx, visitor, e.iterable())?;
+            walk_expr(cx, visitor, e.block())?;
+        },
+        ExprKind::Loop(e) => {
+            walk_expr(cx, visitor, e.block())?;
+        },
+        ExprKind::While(e) => {
+            walk_expr(cx, visitor, e.condition())?;
+            walk_expr(cx, visitor, e.block())?;
+        },
+        ExprKind::Await(e) => {
+            walk_expr(cx, visitor, e.expr())?;
+        },
+        ExprKind::IntLit(_)
+        | ExprKind::FloatLit(_)
+        | ExprKind::StrLit(_)
+        | ExprKind::CharLit(_)
+        | ExprKind::BoolLit(_)
+        | ExprKind::Unstable(_)
+        | ExprKind::Path(_)
+        | ExprKind::Continue(_) => {
+            // These expressions have no sub nodes, which are visited by this visitor
+        },
+    }
+
+    visitor.visit_expr_post(cx, expr)
+}
+
+pub fn walk_pat<'ast, B>(
+    cx: &'ast AstContext<'ast>,
+    visitor: &mut dyn Visitor<'ast, B>,
+    pat: PatKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_pat(cx, pat)?;
+
+    match pat {
+        PatKind::Ident(p) => {
+            if let Some(subpattern) = p.subpattern() {
+                walk_pat(cx, visitor, subpattern)?;
+            }
+        },
+        PatKind::Ref(p) => {
+            walk_pat(cx, visitor, p.pattern())?;
+        },
+        PatKind::Struct(p) => {
+            for field in p.fields() {
+                walk_pat(cx, visitor, field.pat())?;
+            }
+        },
+        PatKind::Tuple(p) => {
+            for el in p.elements() {
+                walk_pat(cx, visitor, *el)?;
+            }
+        },
+        PatKind::Slice(p) => {
+            for el in p.elements() {
+                walk_pat(cx, visitor, *el)?;
+            }
+        },
+        PatKind::Or(p) => {
+            for alt in p.patterns() {
+                walk_pat(cx, visitor, *alt)?;
+            }
+        },
+        PatKind::Wildcard(_)
+        | PatKind::Rest(_)
+        | PatKind::Place(..)
+        | PatKind::Lit(..)
+        | PatKind::Path(_)
+        | PatKind::Range(_)
+        | PatKind::Unstable(_) => {
+            // These patterns have no sub patterns, which are visited by this visitor
+        },
+    }
+
+    ControlFlow::Continue(())
+}
+
+/// Calls the given closure for every expression in the body, including the
+/// body expression itself. The expressions are visited in post-order, meaning
+/// that sub expressions are visited before the expression containing them.
+///
+/// The traversal stops, once the closure returns [`ControlFlow::Break`]. The
+/// value of the break is then returned.
+///
+/// ```
+/// # use std::ops::ControlFlow;
+/// # use marker_api::ast::{expr::{ExprKind, ReturnExpr}, item::Body, visitor::for_each_expr};
+/// # use marker_api::AstContext;
+/// // Find the first `return` expression in the body
+/// fn find_return<'ast>(cx: &'ast AstContext<'ast>, body: &'ast Body<'ast>) -> Option<&'ast ReturnExpr<'ast>> {
+///     for_each_expr(cx, body, |expr| match expr {
+///         ExprKind::Return(ret) => ControlFlow::Break(ret),
+///         _ => ControlFlow::Continue(()),
+///     })
+/// }
+/// ```
+pub fn for_each_expr<'ast, B, F>(cx: &'ast AstContext<'ast>, body: &'ast Body<'ast>, f: F) -> Option<B>
+where
+    F: FnMut(ExprKind<'ast>) -> ControlFlow<B>,
+{
+    struct ExprVisitor<F>(F);
+
+    impl<'ast, B, F: FnMut(ExprKind<'ast>) -> ControlFlow<B>> Visitor<'ast, B> for ExprVisitor<F> {
+        fn visit_expr_post(&mut self, _cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> ControlFlow<B> {
+            (self.0)(expr)
+        }
+    }
+
+    match walk_body(cx, &mut ExprVisitor(f), body) {
+        ControlFlow::Break(value) => Some(value),
+        ControlFlow::Continue(()) => None,
+    }
+}
@@ -2,13 +2,15 @@
 //! Items in this module are generally unstable, with the exception of the
 //! exposed interface of [`AstContext`].
 
-use std::{cell::RefCell, mem::transmute};
+use std::{cell::RefCell, marker::PhantomData, mem::transmute, ops::ControlFlow};
 
 use crate::{
     ast::{
-        item::{Body, ItemKind},
-        ty::SemTyKind,
-        BodyId, ExprId, ItemId, Span, SpanId, SymbolId, TyDefId,
+        expr::{ConstExpr, ExprKind},
+        item::{Body, ItemKind, VisibilityKind},
+        ty::{SemAdtTy, SemTyKind},
+        Attr, BodyId, ConstValue, ExprId, FilePos, ItemId, LineBounds, MacroInfo, MacroOrigin, Span, SpanId,
+        SpanSource, SpanSrcId, SymbolId, TyDefId, TyPath,
     },
     diagnostic::{Diagnostic, DiagnosticBuilder, EmissionNode},
     ffi,
@@ -36,13 +38,38 @@ thread_local! {
 ///
 /// See: `./docs/internal/driver-info.md` for more context
 #[doc(hidden)]
-pub fn set_ast_cx<'ast>(cx: &'ast AstContext<'ast>) {
+pub fn set_ast_cx<'ast>(cx: &'ast AstContext<'ast>) -> AstCxGuard<'ast> {
     // Safety:
     // This `transmute` erases the `'ast` lifetime. This is uncool, but sadly
     // necessary to store the reference [`AST_CX`]. All accesses are guarded by
     // the [`with_cx`] function, which resets the lifetime to <= `'ast`.
     let cx_static: &'static AstContext<'static> = unsafe { transmute(cx) };
     AST_CX.with(|cx| cx.replace(Some(cx_static)));
+    AstCxGuard { _lifetime: PhantomData }
+}
+
+/// **Warning**
+///
+/// This function is unstable and only exported, to enable the adapter to unset
+/// the [`AstContext`] of a lint crate, once the `'ast` lifetime ends.
+#[doc(hidden)]
+pub fn clear_ast_cx() {
+    AST_CX.with(|cx| cx.replace(None));
+}
+
+/// This guard is returned by [`set_ast_cx`] and unsets the [`AstContext`] when
+/// it's dropped. This ensures that the context can't be accessed after the end
+/// of the `'ast` lifetime, even if the stack is unwound by a panic.
+#[doc(hidden)]
+#[must_use = "the context is unset, once the guard is dropped"]
+pub struct AstCxGuard<'ast> {
+    _lifetime: PhantomData<&'ast AstContext<'ast>>,
+}
+
+impl Drop for AstCxGuard<'_> {
+    fn drop(&mut self) {
+        clear_ast_cx();
+    }
 }
 
 /// This function provides the current [`AstContext`]. This function requires an
@@ -115,6 +142,15 @@ impl<'ast> AstContext<'ast> {
         self.driver.call_lint_level_at(lint, node.into())
     }
 
+    /// This emits a diagnostic for the given lint, node and message. The
+    /// `decorate` closure can be used to add further information to the
+    /// diagnostic.
+    ///
+    /// Returns `true`, if the diagnostic was emitted. It returns `false`, if the
+    /// lint is allowed at the given node, if the span comes from a macro and the
+    /// lint doesn't report in macros, or if the driver suppressed the
+    /// diagnostic. The `decorate` closure is only called for emitted
+    /// diagnostics.
     #[allow(clippy::needless_pass_by_value)] // `&impl ToString`
     pub fn emit_lint<F>(
         &self,
@@ -123,22 +159,117 @@ impl<'ast> AstContext<'ast> {
         msg: impl ToString,
         span: &Span<'ast>,
         decorate: F,
-    ) where
+    ) -> bool
+    where
         F: FnOnce(&mut DiagnosticBuilder<'ast>),
     {
-        if matches!(lint.report_in_macro, MacroReport::No) && span.is_from_macro() {
-            return;
+        let ControlFlow::Continue(macro_origin) = self.check_macro_report(lint, span) else {
+            return false;
+        };
+        let node = node.into();
+        if self.lint_level_at(lint, node) == Level::Allow {
+            return false;
         }
+        let mut builder = DiagnosticBuilder::new(lint, node, msg.to_string(), span.clone());
+        decorate(&mut builder);
+        self.add_macro_note(&mut builder, macro_origin);
+        builder.emit(self)
+    }
+
+    /// This emits a single diagnostic for one logical issue, which occurs at
+    /// multiple locations. The first reported span is used as the main span of
+    /// the diagnostic. All other spans are added as spanned notes. The `decorate`
+    /// closure can be used to add further information to the diagnostic.
+    ///
+    /// Spans from macros are filtered, depending on the [`MacroReport`]
+    /// setting of the lint, like in [`emit_lint`](Self::emit_lint). Returns
+    /// `true`, if the diagnostic was emitted. It returns `false`, if the lint
+    /// is allowed at the given node, if no span remains after filtering, or if
+    /// the driver suppressed the diagnostic.
+    #[allow(clippy::needless_pass_by_value)] // `&impl ToString`
+    pub fn emit_lint_for_each_span<'s, I, F>(
+        &self,
+        lint: &'static Lint,
+        node: impl Into<EmissionNode>,
+        msg: impl ToString,
+        spans: I,
+        decorate: F,
+    ) -> bool
+    where
+        'ast: 's,
+        I: IntoIterator<Item = &'s Span<'ast>>,
+        F: FnOnce(&mut DiagnosticBuilder<'ast>),
+    {
         let node = node.into();
-        if self.lint_level_at(lint, node) != Level::Allow {
-            let mut builder = DiagnosticBuilder::new(lint, node, msg.to_string(), span.clone());
-            decorate(&mut builder);
-            builder.emit(self);
+        if self.lint_level_at(lint, node) == Level::Allow {
+            return false;
+        }
+        let mut spans = spans
+            .into_iter()
+            .filter_map(|span| match self.check_macro_report(lint, span) {
+                ControlFlow::Continue(macro_origin) => Some((span, macro_origin)),
+                ControlFlow::Break(()) => None,
+            });
+        let Some((main_span, macro_origin)) = spans.next() else {
+            return false;
+        };
+
+        let mut builder = DiagnosticBuilder::new(lint, node, msg.to_string(), main_span.clone());
+        for (span, _) in spans {
+            builder.span_note("the same issue occurs here", span);
+        }
+        decorate(&mut builder);
+        self.add_macro_note(&mut builder, macro_origin);
+        builder.emit(self)
+    }
+
+    /// Checks if the lint should be reported at the given span, according to the
+    /// [`MacroReport`] setting of the lint. [`ControlFlow::Break`] is returned, if
+    /// the lint should not be reported. Otherwise, the returned value contains the
+    /// macro origin, if the diagnostic should have a macro note.
+    fn check_macro_report(&self, lint: &'static Lint, span: &Span<'ast>) -> ControlFlow<(), Option<MacroOrigin>> {
+        if !span.is_from_macro() {
+            return ControlFlow::Continue(None);
         }
+        match lint.report_in_macro {
+            MacroReport::No => ControlFlow::Break(()),
+            MacroReport::All => ControlFlow::Continue(None),
+            MacroReport::YesWithMacroNote => match self.driver.call_span_macro_origin(span) {
+                Some(origin) if origin.is_local => ControlFlow::Continue(Some(origin)),
+                _ => ControlFlow::Break(()),
+            },
+        }
+    }
+
+    fn add_macro_note(&self, builder: &mut DiagnosticBuilder<'ast>, macro_origin: Option<MacroOrigin>) {
+        if let Some(origin) = macro_origin {
+            builder.span_note(
+                format!(
+                    "this was triggered by an expansion of the `{}!` macro",
+                    self.symbol_str(origin.name)
+                ),
+                self.span(origin.call_site),
+            );
+        }
+    }
+
+    /// This function creates a code suggestion, by replacing all occurrences of
+    /// `{span}` in the template with the code snippet of the given [`Span`].
+    /// `None` is returned, if the snippet is unavailable. This allows lints to
+    /// skip the suggestion or lower the [`Applicability`](crate::diagnostic::Applicability),
+    /// instead of silently suggesting code with placeholders.
+    ///
+    /// ```ignore
+    /// // Given an expression `value.len()`, with `span` pointing to `value`
+    /// cx.format_with_span("{span}.is_empty()", span) // -> Some("value.is_empty()")
+    /// ```
+    pub fn format_with_span(&self, template: &str, span: &Span<'ast>) -> Option<String> {
+        let snippet = self.span_snipped(span)?;
+        Some(template.replace("{span}", &snippet))
     }
 
-    pub(crate) fn emit_diagnostic<'a>(&self, diag: &'a Diagnostic<'a, 'ast>) {
-        self.driver.call_emit_diagnostic(diag);
+    pub(crate) fn emit_diagnostic<'a>(&self, diag: &'a Diagnostic<'a, 'ast>) -> bool {
+        self.driver.call_emit_diagnostic(diag)
     }
 
     /// This returns the [`ItemKind`] belonging to the given [`ItemId`]. It can
@@ -150,11 +281,296 @@ impl<'ast> AstContext<'ast> {
         self.driver.call_item(id)
     }
 
+    /// Returns the [`ItemId`] of the item, which contains the given item. This
+    /// can be a module, trait, impl block or the item owning a body, like a
+    /// function. `None` is returned for items declared at the crate root, since
+    /// the crate itself is represented by [`Crate`](crate::ast::item::Crate)
+    /// and doesn't have an [`ItemId`].
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will only return parents for items of the current crate.
+    pub fn parent_item(&self, id: ItemId) -> Option<ItemId> {
+        self.driver.call_parent_item(id)
+    }
+
+    /// Returns information about the macro expansion with the given
+    /// [`SpanSrcId`], like the name of the macro and the span of the macro
+    /// call. The id can be retrieved with [`Span::src_id`].
+    ///
+    /// `None` is returned, if the id belongs to a desugaring and not to a macro
+    /// expansion.
+    pub fn macro_info(&self, src_id: SpanSrcId) -> Option<&'ast MacroInfo<'ast>> {
+        self.driver.call_macro_info(src_id)
+    }
+
+    /// Returns an iterator over the [`ItemId`]s of all items in the analyzed
+    /// crate. This includes items nested in modules and function bodies. Items
+    /// from external dependencies are excluded.
+    ///
+    /// Associated items are not yielded on their own. They can be accessed via
+    /// their trait or impl item.
+    ///
+    /// The items are yielded in a deterministic order, which follows the module
+    /// tree in preorder. The order of items inside a module follows the source.
+    pub fn all_items(&self) -> impl Iterator<Item = ItemId> + '_ {
+        self.driver.call_all_items().iter().copied()
+    }
+
+    /// Returns the declared visibility of the item belonging to the given
+    /// [`ItemId`]. See [`VisibilityKind`] for more information.
+    pub fn item_visibility(&self, id: ItemId) -> VisibilityKind {
+        self.driver.call_item_visibility(id)
+    }
+
+    /// Returns the [`Span`] of the declaration of the item belonging to the given
+    /// [`ItemId`]. This is cheaper than fetching the item with [`AstContext::item`],
+    /// and it also works for items from other crates.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will return the span of the item header, like `pub struct Foo`, without the
+    ///   body. `None` is returned, if the driver has no span for the item.
+    pub fn definition_span(&self, id: ItemId) -> Option<&'ast Span<'ast>> {
+        self.driver.call_definition_span(id).map(|span_id| self.span(span_id))
+    }
+
+    /// Returns the canonical path of the item belonging to the given [`ItemId`],
+    /// like `std::collections::hash::map::HashMap`. The path starts with the name
+    /// of the crate, which defines the item, and follows the location of the
+    /// definition. Re-exports are not considered. For types, the returned path can
+    /// be used with [`AstContext::resolve_ty_ids`].
+    ///
+    /// `None` is returned for items without a stable path. This includes anonymous
+    /// items, like impl blocks and `const _` items, as well as items nested inside
+    /// them or inside function bodies.
+    pub fn resolve_item_path(&self, id: ItemId) -> Option<String> {
+        self.driver.call_resolve_item_path(id).map(ToString::to_string)
+    }
+
+    /// Resolves the function or method called by the expression belonging to the
+    /// given [`ExprId`]. This works for [`CallExpr`](crate::ast::expr::CallExpr)s
+    /// and [`MethodExpr`](crate::ast::expr::MethodExpr)s. Calls of trait methods
+    /// resolve to the method declared in the trait, and not to the method in the
+    /// impl block.
+    ///
+    /// `None` is returned, if the expression isn't a call, or if the callee can't
+    /// be resolved statically. This is the case for calls of function pointers,
+    /// closures and tuple struct or variant constructors.
+    ///
+    /// ```ignore
+    /// if let Some(callee) = cx.callee_of(expr.id()) {
+    ///     if cx.resolve_item_path(callee).as_deref() == Some("core::mem::forget") {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn callee_of(&self, expr_id: ExprId) -> Option<ItemId> {
+        self.driver.call_callee_of(expr_id)
+    }
+
+    /// Evaluates the given constant expression. `None` is returned, if the
+    /// expression can't be evaluated, for example, if it depends on generic
+    /// parameters.
+    ///
+    /// ```ignore
+    /// if let SynTyKind::Array(array) = ty {
+    ///     if let Some(len) = array.len() {
+    ///         if cx.resolve_const_value(len) == Some(ConstValue::Integer(0)) {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_const_value(&self, expr: &ConstExpr<'ast>) -> Option<ConstValue<'ast>> {
+        self.driver.call_resolve_const_value(expr.expr().id())
+    }
+
+    /// Returns the [`Body`] belonging to the given [`BodyId`].
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the driver doesn't know the given [`BodyId`]. The panic can't
+    /// unwind through the `extern "C"` functions between the lint crate and the
+    /// driver, which will therefore abort the process. [`AstContext::try_body`]
+    /// returns the error instead.
     pub fn body(&self, id: BodyId) -> &Body<'ast> {
+        match self.driver.call_body(id) {
+            Ok(body) => body,
+            Err(msg) => panic!("{msg}"),
+        }
+    }
+
+    /// Returns the [`Body`] belonging to the given [`BodyId`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error message of the driver, if the [`BodyId`] is unknown.
+    pub fn try_body(&self, id: BodyId) -> Result<&Body<'ast>, &'ast str> {
         self.driver.call_body(id)
     }
 
+    /// This returns the [`ExprKind`] belonging to the given [`ExprId`]. This
+    /// can be used to retrieve expressions, which have been stored by their
+    /// id, for instance, in a `HashMap`.
+    ///
+    /// `None` is returned, if the expression is not available, for example, if
+    /// the [`ExprId`] belongs to a body that has not been converted yet.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will only return expressions, which have already been passed to a lint
+    ///   crate or been requested as part of a [`Body`].
+    pub fn expr(&self, id: ExprId) -> Option<ExprKind<'ast>> {
+        self.driver.call_expr(id)
+    }
+
+    /// Returns `true`, if the [`Body`] belonging to the given [`BodyId`] passes
+    /// the borrow checker.
+    ///
+    /// Lints suggesting refactorings, like inlining a variable, can use this to
+    /// be explicit about the assumption that the linted code is accepted by the
+    /// borrow checker.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will always return `true`, as lint crates are only called after the borrow
+    ///   checker has run successfully.
+    pub fn body_passes_borrow_check(&self, _id: BodyId) -> bool {
+        true
+    }
+
+    /// Returns `true`, if the item belonging to the given [`ItemId`] has been
+    /// marked as deprecated with the `#[deprecated]` attribute.
+    ///
+    /// [`AstContext::deprecated_since`] and [`AstContext::deprecated_note`] can
+    /// be used to get more information about the deprecation.
+    pub fn is_deprecated(&self, id: ItemId) -> bool {
+        self.driver.call_item_deprecation(id).is_some()
+    }
+
+    /// Returns the version, from which the item belonging to the given [`ItemId`]
+    /// is deprecated, as written in the `since` field of the `#[deprecated]`
+    /// attribute. `None` is returned, if the item is not deprecated, or if the
+    /// field hasn't been specified.
+    ///
+    /// ```
+    /// #[deprecated(since = "1.2.0", note = "use `bar` instead")]
+    /// //                   ^^^^^^^ The returned version
+    /// pub fn foo() {}
+    /// ```
+    pub fn deprecated_since(&self, id: ItemId) -> Option<&'ast str> {
+        self.driver
+            .call_item_deprecation(id)
+            .and_then(|deprecation| deprecation.since.copy())
+            .map(|since| since.get())
+    }
+
+    /// Returns the note of the `#[deprecated]` attribute of the item belonging
+    /// to the given [`ItemId`]. The note usually explains the deprecation or
+    /// suggests alternatives. `None` is returned, if the item is not deprecated,
+    /// or if no note has been specified.
+    ///
+    /// ```
+    /// #[deprecated(since = "1.2.0", note = "use `bar` instead")]
+    /// //                                   ^^^^^^^^^^^^^^^^^^ The returned note
+    /// pub fn foo() {}
+    /// ```
+    pub fn deprecated_note(&self, id: ItemId) -> Option<&'ast str> {
+        self.driver
+            .call_item_deprecation(id)
+            .and_then(|deprecation| deprecation.note.copy())
+            .map(|note| note.get())
+    }
+
+    /// Returns all outer attributes attached to the item belonging to the given
+    /// [`ItemId`]. Doc comments are included as `doc` attributes.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will not return attributes which have been removed during macro expansion,
+    ///   like `#[derive]` and `#[cfg]`.
+    pub fn item_attrs(&self, id: ItemId) -> &'ast [Attr<'ast>] {
+        self.driver.call_item_attrs(id)
+    }
+
+    /// Returns `true`, if the item belonging to the given [`ItemId`] has an
+    /// attribute with the given path. The segments of the path are separated by
+    /// `::`, like `rustfmt::skip`.
+    ///
+    /// ```ignore
+    /// if cx.has_attr(item.id(), "must_use") {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn has_attr(&self, id: ItemId, path: &str) -> bool {
+        self.item_attrs(id).iter().any(|attr| attr.is_path(path))
+    }
+
+    /// Returns `true`, if the given type implements the trait belonging to the
+    /// given [`TyDefId`]. The id of a trait can be obtained via
+    /// [`AstContext::resolve_ty_ids`]. The generic arguments of the trait,
+    /// excluding the `Self` type, are provided by `args`. For example, checking
+    /// if `u16` implements `From<u8>` would pass `u16` as `ty`, the id of `From`
+    /// as `trait_id` and `[u8]` as `args`.
+    ///
+    /// The check respects blanket and negative impls. For trait aliases, the type
+    /// has to satisfy all bounds of the alias.
+    ///
+    /// `false` is returned, if the [`TyDefId`] doesn't belong to a trait or trait
+    /// alias, or if the number of arguments doesn't match the trait.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will return `false` for types which can't be fully represented by semantic
+    ///   types, like generic parameters. Lifetimes are ignored for this check.
+    pub fn type_implements_trait(&self, ty: SemTyKind<'ast>, trait_id: TyDefId, args: &[SemTyKind<'ast>]) -> bool {
+        self.driver.call_type_implements_trait(ty, trait_id, args)
+    }
+
+    /// Returns the [`ItemId`]s of all impl items, which implement the trait
+    /// belonging to the given [`TyDefId`]. Each returned id belongs to an
+    /// [`ImplItem`](crate::ast::item::ImplItem). An empty slice is returned, if
+    /// the id doesn't belong to a trait.
+    ///
+    /// The returned ids are unordered. The slice should not be stored across
+    /// `check_*` calls.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will only return impls of the current crate.
+    pub fn trait_impls(&self, trait_id: TyDefId) -> &[ItemId] {
+        self.driver.call_trait_impls(trait_id)
+    }
+
+    /// Returns `true`, if the given type implements [`Copy`]. This is a shorthand
+    /// for [`AstContext::type_implements_trait`], which doesn't require the id of
+    /// the trait.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will return `false` for types which can't be fully represented by semantic
+    ///   types, like generic parameters.
+    pub fn is_type_copy(&self, ty: SemTyKind<'ast>) -> bool {
+        self.driver.call_check_trait_impl(ty, KnownTrait::Copy)
+    }
+
+    /// Returns `true`, if the given type implements [`Send`]. This is a shorthand
+    /// for [`AstContext::type_implements_trait`], which doesn't require the id of
+    /// the trait.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will return `false` for types which can't be fully represented by semantic
+    ///   types, like generic parameters.
+    pub fn is_type_send(&self, ty: SemTyKind<'ast>) -> bool {
+        self.driver.call_check_trait_impl(ty, KnownTrait::Send)
+    }
+
+    /// Returns `true`, if the given type implements [`Sync`]. This is a shorthand
+    /// for [`AstContext::type_implements_trait`], which doesn't require the id of
+    /// the trait.
+    ///
+    /// #### Driver information
+    /// * Rustc's driver will return `false` for types which can't be fully represented by semantic
+    ///   types, like generic parameters.
+    pub fn is_type_sync(&self, ty: SemTyKind<'ast>) -> bool {
+        self.driver.call_check_trait_impl(ty, KnownTrait::Sync)
+    }
+
     /// This function tries to resolve the given path to the corresponding [`TyDefId`].
+    /// The path can be given as a string, like `"std::vec::Vec"`, or as a [`TyPath`].
     ///
     /// The slice might be empty if the path could not be resolved. This could be
     /// due to an error in the path or because the linted crate doesn't have the
@@ -175,8 +591,12 @@ impl<'ast> AstContext<'ast> {
     ///     }
     /// }
     /// ```
-    pub fn resolve_ty_ids(&self, path: &str) -> &[TyDefId] {
-        (self.driver.resolve_ty_ids)(self.driver.driver_context, path.into()).get()
+    pub fn resolve_ty_ids<'a>(&self, path: impl Into<TyPath<'a>>) -> &[TyDefId] {
+        let path = path.into();
+        if path.segments().is_empty() {
+            return &[];
+        }
+        self.driver.call_resolve_ty_ids(&path.to_string())
     }
 }
 
@@ -195,13 +615,20 @@ impl<'ast> AstContext<'ast> {
         self.driver.call_span(span_id)
     }
 
+    pub(crate) fn span_pos_to_file_pos(&self, file: &SpanSource<'ast>, pos: usize) -> Option<FilePos> {
+        self.driver.call_span_pos_to_file_pos(file, pos)
+    }
+
+    pub(crate) fn span_pos_to_line_bounds(&self, file: &SpanSource<'ast>, pos: usize) -> Option<LineBounds> {
+        self.driver.call_span_pos_to_line_bounds(file, pos)
+    }
+
     pub(crate) fn symbol_str(&self, sym: SymbolId) -> &'ast str {
         self.driver.call_symbol_str(sym)
     }
 
-    #[allow(unused)] // Will be used later(or removed)
-    pub(crate) fn resolve_method_target(&self, expr: ExprId) -> ItemId {
-        self.driver.resolve_method_target(expr)
+    pub(crate) fn field_ty_of(&self, adt: &'ast SemAdtTy<'ast>, name: &str) -> Option<SemTyKind<'ast>> {
+        self.driver.call_field_ty_of(adt, name)
     }
 }
 
@@ -230,57 +657,165 @@ struct DriverCallbacks<'ast> {
     /// get its own context.
     pub driver_context: &'ast (),
 
-    // FIXME: all of the callbacks here must be marked as `unsafe`, because you
-    // can't call them in safe Rust passing a &() pointer. This will trigger UB.
+    // All callbacks are `unsafe`, since they cast the `driver_context` back
+    // into the driver-specific type. Calling them with any other pointer than
+    // the `driver_context` of this struct is UB.
 
     // Lint emission and information
-    pub lint_level_at: extern "C" fn(&'ast (), &'static Lint, EmissionNode) -> Level,
-    pub emit_diag: for<'a> extern "C" fn(&'ast (), &'a Diagnostic<'a, 'ast>),
+    pub lint_level_at: unsafe extern "C" fn(&'ast (), &'static Lint, EmissionNode) -> Level,
+    pub emit_diag: for<'a> unsafe extern "C" fn(&'ast (), &'a Diagnostic<'a, 'ast>) -> bool,
 
     // Public utility
-    pub item: extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiOption<ItemKind<'ast>>,
-    pub body: extern "C" fn(&'ast (), id: BodyId) -> &'ast Body<'ast>,
-
-    pub resolve_ty_ids: extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId>,
+    pub item: unsafe extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiOption<ItemKind<'ast>>,
+    pub parent_item: unsafe extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiOption<ItemId>,
+    pub all_items: unsafe extern "C" fn(&'ast ()) -> ffi::FfiSlice<'ast, ItemId>,
+    pub macro_info: unsafe extern "C" fn(&'ast (), SpanSrcId) -> ffi::FfiOption<&'ast MacroInfo<'ast>>,
+    pub item_visibility: unsafe extern "C" fn(&'ast (), id: ItemId) -> VisibilityKind,
+    pub definition_span: unsafe extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiOption<SpanId>,
+    pub resolve_item_path: unsafe extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiOption<ffi::FfiStr<'ast>>,
+    pub body: unsafe extern "C" fn(&'ast (), id: BodyId) -> ffi::FfiResult<&'ast Body<'ast>, ffi::FfiStr<'ast>>,
+    pub expr: unsafe extern "C" fn(&'ast (), id: ExprId) -> ffi::FfiOption<ExprKind<'ast>>,
+    pub callee_of: unsafe extern "C" fn(&'ast (), id: ExprId) -> ffi::FfiOption<ItemId>,
+    pub resolve_const_value: unsafe extern "C" fn(&'ast (), id: ExprId) -> ffi::FfiOption<&'ast ConstValue<'ast>>,
+
+    pub item_deprecation: unsafe extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiOption<ItemDeprecation<'ast>>,
+    pub item_attrs: unsafe extern "C" fn(&'ast (), id: ItemId) -> ffi::FfiSlice<'ast, Attr<'ast>>,
+    pub resolve_ty_ids: unsafe extern "C" fn(&'ast (), path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId>,
+    pub type_implements_trait:
+        unsafe extern "C" fn(&'ast (), SemTyKind<'ast>, TyDefId, ffi::FfiSlice<'_, SemTyKind<'ast>>) -> bool,
+    pub trait_impls: unsafe extern "C" fn(&'ast (), TyDefId) -> ffi::FfiSlice<'ast, ItemId>,
+    pub check_trait_impl: unsafe extern "C" fn(&'ast (), SemTyKind<'ast>, KnownTrait) -> bool,
 
     // Internal utility
-    pub expr_ty: extern "C" fn(&'ast (), ExprId) -> SemTyKind<'ast>,
-    pub span: extern "C" fn(&'ast (), SpanId) -> &'ast Span<'ast>,
-    pub span_snippet: extern "C" fn(&'ast (), &Span<'ast>) -> ffi::FfiOption<ffi::FfiStr<'ast>>,
-    pub symbol_str: extern "C" fn(&'ast (), SymbolId) -> ffi::FfiStr<'ast>,
-    pub resolve_method_target: extern "C" fn(&'ast (), ExprId) -> ItemId,
+    pub expr_ty: unsafe extern "C" fn(&'ast (), ExprId) -> SemTyKind<'ast>,
+    pub field_ty_of:
+        unsafe extern "C" fn(&'ast (), &'ast SemAdtTy<'ast>, ffi::FfiStr<'_>) -> ffi::FfiOption<SemTyKind<'ast>>,
+    pub span: unsafe extern "C" fn(&'ast (), SpanId) -> &'ast Span<'ast>,
+    pub span_snippet: unsafe extern "C" fn(&'ast (), &Span<'ast>) -> ffi::FfiOption<ffi::FfiStr<'ast>>,
+    pub span_macro_origin: unsafe extern "C" fn(&'ast (), &Span<'ast>) -> ffi::FfiOption<MacroOrigin>,
+    pub span_pos_to_file_pos: unsafe extern "C" fn(&'ast (), &SpanSource<'ast>, usize) -> ffi::FfiOption<FilePos>,
+    pub span_pos_to_line_bounds: unsafe extern "C" fn(&'ast (), &SpanSource<'ast>, usize) -> ffi::FfiOption<LineBounds>,
+    pub symbol_str: unsafe extern "C" fn(&'ast (), SymbolId) -> ffi::FfiStr<'ast>,
 }
 
+// Safety:
+// The `call_*` functions always pass the `driver_context` of this struct to the
+// callbacks. This is the context, which was provided together with the
+// callbacks by the driver. It's therefore safe to call them.
 impl<'ast> DriverCallbacks<'ast> {
     fn call_lint_level_at(&self, lint: &'static Lint, node: EmissionNode) -> Level {
-        (self.lint_level_at)(self.driver_context, lint, node)
+        unsafe { (self.lint_level_at)(self.driver_context, lint, node) }
     }
 
-    fn call_emit_diagnostic<'a>(&self, diag: &'a Diagnostic<'a, 'ast>) {
-        (self.emit_diag)(self.driver_context, diag);
+    fn call_emit_diagnostic<'a>(&self, diag: &'a Diagnostic<'a, 'ast>) -> bool {
+        unsafe { (self.emit_diag)(self.driver_context, diag) }
     }
 
     fn call_item(&self, id: ItemId) -> Option<ItemKind<'ast>> {
-        (self.item)(self.driver_context, id).copy()
+        unsafe { (self.item)(self.driver_context, id) }.copy()
     }
-    fn call_body(&self, id: BodyId) -> &'ast Body<'ast> {
-        (self.body)(self.driver_context, id)
+    fn call_parent_item(&self, id: ItemId) -> Option<ItemId> {
+        unsafe { (self.parent_item)(self.driver_context, id) }.copy()
+    }
+    fn call_macro_info(&self, src_id: SpanSrcId) -> Option<&'ast MacroInfo<'ast>> {
+        unsafe { (self.macro_info)(self.driver_context, src_id) }.copy()
+    }
+    fn call_all_items(&self) -> &'ast [ItemId] {
+        unsafe { (self.all_items)(self.driver_context) }.get()
+    }
+    fn call_item_visibility(&self, id: ItemId) -> VisibilityKind {
+        unsafe { (self.item_visibility)(self.driver_context, id) }
+    }
+    fn call_definition_span(&self, id: ItemId) -> Option<SpanId> {
+        unsafe { (self.definition_span)(self.driver_context, id) }.copy()
+    }
+    fn call_resolve_item_path(&self, id: ItemId) -> Option<&'ast str> {
+        unsafe { (self.resolve_item_path)(self.driver_context, id) }
+            .copy()
+            .map(|path| path.get())
+    }
+    fn call_body(&self, id: BodyId) -> Result<&'ast Body<'ast>, &'ast str> {
+        unsafe { (self.body)(self.driver_context, id) }
+            .copy()
+            .map_err(|msg| msg.get())
+    }
+    fn call_expr(&self, id: ExprId) -> Option<ExprKind<'ast>> {
+        unsafe { (self.expr)(self.driver_context, id) }.copy()
+    }
+    fn call_callee_of(&self, id: ExprId) -> Option<ItemId> {
+        unsafe { (self.callee_of)(self.driver_context, id) }.copy()
+    }
+    fn call_resolve_const_value(&self, id: ExprId) -> Option<ConstValue<'ast>> {
+        unsafe { (self.resolve_const_value)(self.driver_context, id) }
+            .copy()
+            .copied()
+    }
+
+    fn call_item_deprecation(&self, id: ItemId) -> Option<ItemDeprecation<'ast>> {
+        unsafe { (self.item_deprecation)(self.driver_context, id) }.copy()
+    }
+    fn call_item_attrs(&self, id: ItemId) -> &'ast [Attr<'ast>] {
+        unsafe { (self.item_attrs)(self.driver_context, id) }.get()
+    }
+
+    fn call_resolve_ty_ids(&self, path: &str) -> &'ast [TyDefId] {
+        unsafe { (self.resolve_ty_ids)(self.driver_context, path.into()) }.get()
+    }
+    fn call_type_implements_trait(&self, ty: SemTyKind<'ast>, trait_id: TyDefId, args: &[SemTyKind<'ast>]) -> bool {
+        unsafe { (self.type_implements_trait)(self.driver_context, ty, trait_id, args.into()) }
+    }
+    fn call_trait_impls(&self, trait_id: TyDefId) -> &'ast [ItemId] {
+        unsafe { (self.trait_impls)(self.driver_context, trait_id) }.get()
+    }
+    fn call_check_trait_impl(&self, ty: SemTyKind<'ast>, known_trait: KnownTrait) -> bool {
+        unsafe { (self.check_trait_impl)(self.driver_context, ty, known_trait) }
     }
 
     fn call_expr_ty(&self, expr: ExprId) -> SemTyKind<'ast> {
-        (self.expr_ty)(self.driver_context, expr)
+        unsafe { (self.expr_ty)(self.driver_context, expr) }
+    }
+    fn call_field_ty_of(&self, adt: &'ast SemAdtTy<'ast>, name: &str) -> Option<SemTyKind<'ast>> {
+        unsafe { (self.field_ty_of)(self.driver_context, adt, name.into()) }.copy()
     }
     fn call_span(&self, span_id: SpanId) -> &'ast Span<'ast> {
-        (self.span)(self.driver_context, span_id)
+        unsafe { (self.span)(self.driver_context, span_id) }
     }
     fn call_span_snippet(&self, span: &Span<'ast>) -> Option<String> {
-        let result: Option<ffi::FfiStr> = (self.span_snippet)(self.driver_context, span).into();
+        let result: Option<ffi::FfiStr> = unsafe { (self.span_snippet)(self.driver_context, span) }.into();
         result.map(|x| x.to_string())
     }
-    fn call_symbol_str(&self, sym: SymbolId) -> &'ast str {
-        (self.symbol_str)(self.driver_context, sym).get()
+    fn call_span_macro_origin(&self, span: &Span<'ast>) -> Option<MacroOrigin> {
+        unsafe { (self.span_macro_origin)(self.driver_context, span) }.copy()
+    }
+    fn call_span_pos_to_file_pos(&self, file: &SpanSource<'ast>, pos: usize) -> Option<FilePos> {
+        unsafe { (self.span_pos_to_file_pos)(self.driver_context, file, pos) }.copy()
     }
-    pub fn resolve_method_target(&self, expr: ExprId) -> ItemId {
-        (self.resolve_method_target)(self.driver_context, expr)
+    fn call_span_pos_to_line_bounds(&self, file: &SpanSource<'ast>, pos: usize) -> Option<LineBounds> {
+        unsafe { (self.span_pos_to_line_bounds)(self.driver_context, file, pos) }.copy()
+    }
+    fn call_symbol_str(&self, sym: SymbolId) -> &'ast str {
+        unsafe { (self.symbol_str)(self.driver_context, sym) }.get()
     }
 }
+
+/// The information of a `#[deprecated]` attribute, as provided by the driver.
+#[repr(C)]
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+struct ItemDeprecation<'ast> {
+    pub since: ffi::FfiOption<ffi::FfiStr<'ast>>,
+    pub note: ffi::FfiOption<ffi::FfiStr<'ast>>,
+}
+
+/// Well-known traits, which can be checked without resolving their id first.
+#[repr(C)]
+#[doc(hidden)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+enum KnownTrait {
+    Copy,
+    Send,
+    Sync,
+}
@@ -2,7 +2,7 @@
 //! [`DiagnosticBuilder`] is the public stable interface, to construct messages.
 
 use crate::{
-    ast::{ExprId, FieldId, ItemId, Span, StmtId, VariantId},
+    ast::{ExprId, FieldId, GenericId, Ident, ItemId, Span, StmtId, VariantId},
     context::AstContext,
     ffi::{FfiSlice, FfiStr},
     lint::Lint,
@@ -16,7 +16,7 @@ pub struct DiagnosticBuilder<'ast> {
     msg: String,
     node: EmissionNode,
     span: Span<'ast>,
-    parts: Vec<DiagnosticPart<String, Span<'ast>>>,
+    parts: Vec<OwnedDiagnosticPart<'ast>>,
 }
 
 #[allow(clippy::needless_pass_by_value)] // `&impl ToString` doesn't work
@@ -46,8 +46,9 @@ impl<'ast> DiagnosticBuilder<'ast> {
     /// ```
     ///
     /// [`Self::span_note`] can be used to highlight a relevant [`Span`].
-    pub fn note(&mut self, msg: impl ToString) {
+    pub fn note(&mut self, msg: impl ToString) -> &mut Self {
         self.parts.push(DiagnosticPart::Note { msg: msg.to_string() });
+        self
     }
 
     /// This function adds a note with a [`Span`] to the diagnostic message.
@@ -70,11 +71,12 @@ impl<'ast> DiagnosticBuilder<'ast> {
     /// ```
     ///
     /// [`Self::note`] can be used to add text notes without a span.
-    pub fn span_note(&mut self, msg: impl ToString, span: &Span<'ast>) {
+    pub fn span_note(&mut self, msg: impl ToString, span: &Span<'ast>) -> &mut Self {
         self.parts.push(DiagnosticPart::NoteSpan {
             msg: msg.to_string(),
             span: span.clone(),
         });
+        self
     }
 
     /// This function adds a help message. Help messages are intended to provide
@@ -119,11 +121,34 @@ impl<'ast> DiagnosticBuilder<'ast> {
     ///
     /// [`Self::help`] can be used to add a text help message without a [`Span`].
     /// [`Self::span_suggestion`] can be used to add a help message with a suggestion.
-    pub fn span_help(&mut self, msg: impl ToString, span: &Span<'ast>) {
+    pub fn span_help(&mut self, msg: impl ToString, span: &Span<'ast>) -> &mut Self {
         self.parts.push(DiagnosticPart::HelpSpan {
             msg: msg.to_string(),
             span: span.clone(),
         });
+        self
+    }
+
+    /// This function adds a label to the given [`Span`]. Labels are displayed
+    /// inline, next to the underlined code. They are intended to annotate
+    /// relevant parts of the code with short messages.
+    ///
+    /// From rustc a label would be displayed like this:
+    /// ```text
+    ///  warning: <lint message>
+    ///  --> path/file.rs:1:1
+    ///   |
+    /// 1 | let x = expression;
+    ///   |         ---------- <label>     <-- The label added by this function
+    /// 2 | let y = x;
+    ///   |         ^
+    /// ```
+    pub fn span_label(&mut self, label: impl ToString, span: &Span<'ast>) -> &mut Self {
+        self.parts.push(DiagnosticPart::Label {
+            span: span.clone(),
+            label: label.to_string(),
+        });
+        self
     }
 
     /// This function adds a spanned help message with a suggestion. The suggestion
@@ -148,17 +173,86 @@ impl<'ast> DiagnosticBuilder<'ast> {
         span: &Span<'ast>,
         suggestion: impl ToString,
         app: Applicability,
-    ) {
+    ) -> &mut Self {
         self.parts.push(DiagnosticPart::Suggestion {
             msg: msg.to_string(),
             span: span.clone(),
             sugg: suggestion.to_string(),
             app,
         });
+        self
+    }
+
+    /// This function adds a help message with a suggestion, which consists of
+    /// multiple parts. Each part is a string which replaces the corresponding
+    /// [`Span`]. All parts are applied together and the confidence of the
+    /// suggestion is expressed with the [`Applicability`].
+    ///
+    /// From rustc a multipart suggestion would be displayed like this:
+    /// ```text
+    ///  warning: <lint message>
+    ///  --> path/file.rs:1:1
+    ///   |
+    /// 1 | fn foo(x: &str) -> &str {
+    ///   | ^^^^^^^^^^^^^^^^^^^^^^^
+    ///   |
+    /// help: <msg>                         <--
+    ///   |                                 <--
+    /// 1 | fn foo<'a>(x: &'a str) -> &'a str {  <-- The suggestion added by this function
+    ///   |       ++++     ++          ++   <--
+    /// ```
+    pub fn multipart_suggestion<'span>(
+        &mut self,
+        msg: impl ToString,
+        parts: impl IntoIterator<Item = (&'span Span<'ast>, impl ToString)>,
+        app: Applicability,
+    ) -> &mut Self
+    where
+        'ast: 'span,
+    {
+        self.parts.push(DiagnosticPart::MultipartSuggestion {
+            msg: msg.to_string(),
+            parts: parts
+                .into_iter()
+                .map(|(span, sugg)| SuggestionPart {
+                    span: span.clone(),
+                    sugg: sugg.to_string(),
+                })
+                .collect(),
+            app,
+        });
+        self
     }
 
-    pub(crate) fn emit<'builder>(&'builder self, cx: &AstContext<'ast>) {
-        let parts: Vec<_> = self.parts.iter().map(DiagnosticPart::to_ffi_part).collect();
+    /// This function adds a suggestion to rename the given [`Ident`] to `new_name`.
+    /// Only the span of the identifier is replaced, which makes this suggestion
+    /// [`Applicability::MachineApplicable`]. This is a shorthand for:
+    ///
+    /// ```ignore
+    /// diag.span_suggestion("rename to", ident.span(), new_name, Applicability::MachineApplicable);
+    /// ```
+    ///
+    /// From rustc a rename suggestion would be displayed like this:
+    /// ```text
+    ///  warning: <lint message>
+    ///  --> path/file.rs:1:5
+    ///   |
+    /// 1 | fn FooBar() {}
+    ///   |    ^^^^^^ help: rename to: `foo_bar`      <-- The suggestion added by this function
+    ///   |
+    /// ```
+    pub fn suggest_rename(&mut self, ident: &Ident<'ast>, new_name: impl ToString) -> &mut Self {
+        self.span_suggestion("rename to", ident.span(), new_name, Applicability::MachineApplicable)
+    }
+
+    pub(crate) fn emit<'builder>(&'builder self, cx: &AstContext<'ast>) -> bool {
+        let sugg_parts: Vec<Vec<_>> = self.parts.iter().map(DiagnosticPart::to_ffi_sugg_parts).collect();
+        let parts: Vec<_> = self
+            .parts
+            .iter()
+            .zip(&sugg_parts)
+            .map(|(part, sugg_parts)| part.to_ffi_part(sugg_parts))
+            .collect();
         let diag = Diagnostic {
             lint: self.lint,
             msg: self.msg.as_str().into(),
@@ -166,7 +260,7 @@ impl<'ast> DiagnosticBuilder<'ast> {
             span: &self.span,
             parts: parts.as_slice().into(),
         };
-        cx.emit_diagnostic(&diag);
+        cx.emit_diagnostic(&diag)
     }
 }
 
@@ -179,6 +273,7 @@ pub enum EmissionNode {
     Stmt(StmtId),
     Field(FieldId),
     Variant(VariantId),
+    GenericParam(GenericId),
 }
 
 macro_rules! impl_into_emission_node_for {
@@ -202,12 +297,13 @@ impl_into_emission_node_for!(Item, ItemId);
 impl_into_emission_node_for!(Stmt, StmtId);
 impl_into_emission_node_for!(Field, FieldId);
 impl_into_emission_node_for!(Variant, VariantId);
+impl_into_emission_node_for!(GenericParam, GenericId);
 
 #[repr(C)]
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "driver-api", visibility::make(pub))]
-pub(crate) enum DiagnosticPart<St, Sp> {
+pub(crate) enum DiagnosticPart<St, Sp, Pa> {
     Help {
         msg: St,
     },
@@ -228,10 +324,49 @@ pub(crate) enum DiagnosticPart<St, Sp> {
         sugg: St,
         app: Applicability,
     },
+    MultipartSuggestion {
+        msg: St,
+        parts: Pa,
+        app: Applicability,
+    },
+    Label {
+        span: Sp,
+        label: St,
+    },
 }
 
-impl<'ast> DiagnosticPart<String, Span<'ast>> {
-    fn to_ffi_part<'part>(&'part self) -> DiagnosticPart<FfiStr<'part>, &'part Span<'ast>> {
+/// A single replacement of a multipart suggestion.
+#[repr(C)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) struct SuggestionPart<St, Sp> {
+    pub span: Sp,
+    pub sugg: St,
+}
+
+type OwnedDiagnosticPart<'ast> = DiagnosticPart<String, Span<'ast>, Vec<SuggestionPart<String, Span<'ast>>>>;
+
+type FfiDiagnosticPart<'part, 'ast> =
+    DiagnosticPart<FfiStr<'part>, &'part Span<'ast>, FfiSlice<'part, SuggestionPart<FfiStr<'part>, &'part Span<'ast>>>>;
+
+impl<'ast> OwnedDiagnosticPart<'ast> {
+    fn to_ffi_sugg_parts(&self) -> Vec<SuggestionPart<FfiStr<'_>, &Span<'ast>>> {
+        match self {
+            DiagnosticPart::MultipartSuggestion { parts, .. } => parts
+                .iter()
+                .map(|part| SuggestionPart {
+                    span: &part.span,
+                    sugg: part.sugg.as_str().into(),
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn to_ffi_part<'part>(
+        &'part self,
+        sugg_parts: &'part [SuggestionPart<FfiStr<'part>, &'part Span<'ast>>],
+    ) -> FfiDiagnosticPart<'part, 'ast> {
         match self {
             DiagnosticPart::Help { msg } => DiagnosticPart::Help { msg: msg.into() },
             DiagnosticPart::HelpSpan { msg, span } => DiagnosticPart::HelpSpan { msg: msg.into(), span },
@@ -243,6 +378,15 @@ impl<'ast> DiagnosticPart<String, Span<'ast>> {
                 sugg: sugg.into(),
                 app: *app,
             },
+            DiagnosticPart::MultipartSuggestion { msg, app, .. } => DiagnosticPart::MultipartSuggestion {
+                msg: msg.into(),
+                parts: sugg_parts.into(),
+                app: *app,
+            },
+            DiagnosticPart::Label { span, label } => DiagnosticPart::Label {
+                span,
+                label: label.into(),
+            },
         }
     }
 }
@@ -288,7 +432,7 @@ pub(crate) struct Diagnostic<'builder, 'ast> {
     pub msg: FfiStr<'builder>,
     pub node: EmissionNode,
     pub span: &'builder Span<'ast>,
-    pub parts: FfiSlice<'builder, DiagnosticPart<FfiStr<'builder>, &'builder Span<'ast>>>,
+    pub parts: FfiSlice<'builder, FfiDiagnosticPart<'builder, 'ast>>,
 }
 
 impl<'builder, 'ast> Diagnostic<'builder, 'ast> {
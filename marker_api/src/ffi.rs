@@ -27,6 +27,24 @@ impl<'a> PartialEq for FfiStr<'a> {
     }
 }
 
+impl PartialEq<str> for FfiStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.get() == other
+    }
+}
+
+impl PartialEq<&str> for FfiStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.get() == *other
+    }
+}
+
+impl PartialEq<String> for FfiStr<'_> {
+    fn eq(&self, other: &String) -> bool {
+        self.get() == other
+    }
+}
+
 impl<'a> From<&'a str> for FfiStr<'a> {
     fn from(source: &'a str) -> Self {
         Self {
@@ -62,10 +80,9 @@ impl<'a> From<&FfiStr<'a>> for &'a str {
     }
 }
 
-impl<'a> ToString for FfiStr<'a> {
-    fn to_string(&self) -> String {
-        let base: &str = self.into();
-        base.to_string()
+impl std::fmt::Display for FfiStr<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.get().fmt(f)
     }
 }
 
@@ -108,6 +125,10 @@ impl<T> FfiOption<T> {
     pub fn is_some(&self) -> bool {
         matches!(self, FfiOption::Some(_))
     }
+
+    pub fn is_none(&self) -> bool {
+        matches!(self, FfiOption::None)
+    }
 }
 
 impl<T> From<FfiOption<T>> for Option<T> {
@@ -128,6 +149,68 @@ impl<T> From<Option<T>> for FfiOption<T> {
     }
 }
 
+impl<T> IntoIterator for FfiOption<T> {
+    type Item = T;
+    type IntoIter = std::option::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.copy().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a FfiOption<T> {
+    type Item = &'a T;
+    type IntoIter = std::option::IntoIter<&'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.get().into_iter()
+    }
+}
+
+/// This is an FFI safe result. It's used by driver callbacks, which can fail,
+/// to return the error to the caller instead of panicking inside the driver.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FfiResult<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+impl<T, E> FfiResult<T, E> {
+    pub fn get(&self) -> Result<&T, &E> {
+        match self {
+            FfiResult::Ok(x) => Ok(x),
+            FfiResult::Err(err) => Err(err),
+        }
+    }
+
+    pub fn copy(self) -> Result<T, E> {
+        self.into()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self, FfiResult::Ok(_))
+    }
+}
+
+impl<T, E> From<FfiResult<T, E>> for Result<T, E> {
+    fn from(src: FfiResult<T, E>) -> Self {
+        match src {
+            FfiResult::Ok(t) => Result::Ok(t),
+            FfiResult::Err(err) => Result::Err(err),
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for FfiResult<T, E> {
+    fn from(src: Result<T, E>) -> Self {
+        match src {
+            Result::Ok(t) => FfiResult::Ok(t),
+            Result::Err(err) => FfiResult::Err(err),
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct FfiSlice<'a, T> {
@@ -187,3 +270,42 @@ impl<'a, T: std::fmt::Debug> std::fmt::Debug for FfiSlice<'a, T> {
         f.debug_list().entries(data.iter()).finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ffi_str_eq_str() {
+        let ffi_str = FfiStr::from("marker");
+        assert!(ffi_str == *"marker");
+        assert!(ffi_str != *"marke");
+        assert!(ffi_str != *"markers");
+        assert!(FfiStr::from("") == *"");
+    }
+
+    #[test]
+    fn test_ffi_str_display() {
+        assert_eq!(FfiStr::from("marker").to_string(), "marker");
+        assert_eq!(format!("<{:>8}>", FfiStr::from("api")), "<     api>");
+        assert_eq!(FfiStr::from("").to_string(), "");
+    }
+
+    #[test]
+    fn test_ffi_option_into_iter() {
+        let some = FfiOption::Some(7);
+        assert_eq!((&some).into_iter().collect::<Vec<_>>(), vec![&7]);
+        assert_eq!(some.into_iter().collect::<Vec<_>>(), vec![7]);
+
+        let none: FfiOption<u32> = FfiOption::None;
+        assert_eq!((&none).into_iter().count(), 0);
+        assert_eq!(none.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_ffi_option_is_none() {
+        assert!(FfiOption::<u32>::None.is_none());
+        assert!(!FfiOption::Some(7).is_none());
+        assert!(FfiOption::<u32>::from(None).is_none());
+    }
+}
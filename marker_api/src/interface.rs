@@ -9,15 +9,24 @@ use crate::{context::AstContext, ffi::FfiSlice, lint::Lint};
 #[doc(hidden)]
 pub struct LintCrateBindings {
     pub set_ast_context: for<'ast> extern "C" fn(cx: &'ast AstContext<'ast>),
+    pub clear_ast_context: extern "C" fn(),
 
     // lint pass functions
     pub info: for<'ast> extern "C" fn() -> LintPassInfo,
     pub check_item: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::item::ItemKind<'ast>),
     pub check_field: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::Field<'ast>),
     pub check_variant: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::EnumVariant<'ast>),
+    pub check_generic_param:
+        for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::generic::SynGenericParamKind<'ast>),
     pub check_body: for<'ast> extern "C" fn(&'ast AstContext<'ast>, &'ast crate::ast::item::Body<'ast>),
     pub check_stmt: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::stmt::StmtKind<'ast>),
     pub check_expr: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::expr::ExprKind<'ast>),
+    pub check_pat: for<'ast> extern "C" fn(&'ast AstContext<'ast>, crate::ast::pat::PatKind<'ast>),
+    pub check_fn: for<'ast> extern "C" fn(
+        &'ast AstContext<'ast>,
+        crate::ast::item::FnKind<'ast>,
+        &'ast crate::ast::item::Body<'ast>,
+    ),
 }
 
 /// This macro marks the given struct as the main [`LintPass`](`crate::LintPass`)
@@ -66,6 +75,11 @@ macro_rules! export_lint_pass {
                 $crate::MARKER_API_VERSION
             }
 
+            #[no_mangle]
+            extern "C" fn marker_rustc_version() -> &'static str {
+                $crate::MARKER_RUSTC_VERSION
+            }
+
             /// This magic function fills the `LintCrateBindings` struct to allow easy
             /// communication between marker's driver and lint crates.
             #[no_mangle]
@@ -73,7 +87,12 @@ macro_rules! export_lint_pass {
                 pub use $crate::LintPass;
 
                 extern "C" fn set_ast_context<'ast>(cx: &'ast $crate::AstContext<'ast>) {
-                    $crate::context::set_ast_cx(cx);
+                    // The guard can't be passed over the FFI boundary. The adapter
+                    // instead calls `clear_ast_context` once the lifetime ends.
+                    std::mem::forget($crate::context::set_ast_cx(cx));
+                }
+                extern "C" fn clear_ast_context() {
+                    $crate::context::clear_ast_cx();
                 }
                 extern "C" fn info() -> $crate::LintPassInfo {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().info())
@@ -96,6 +115,12 @@ macro_rules! export_lint_pass {
                 ) {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().check_variant(cx, variant));
                 }
+                extern "C" fn check_generic_param<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    param: &'ast $crate::ast::generic::SynGenericParamKind<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_generic_param(cx, param));
+                }
                 extern "C" fn check_body<'ast>(
                     cx: &'ast $crate::AstContext<'ast>,
                     body: &'ast $crate::ast::item::Body<'ast>,
@@ -114,16 +139,33 @@ macro_rules! export_lint_pass {
                 ) {
                     super::__MARKER_STATE.with(|state| state.borrow_mut().check_expr(cx, expr));
                 }
+                extern "C" fn check_pat<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    pat: $crate::ast::pat::PatKind<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_pat(cx, pat));
+                }
+                extern "C" fn check_fn<'ast>(
+                    cx: &'ast $crate::AstContext<'ast>,
+                    fn_kind: $crate::ast::item::FnKind<'ast>,
+                    body: &'ast $crate::ast::item::Body<'ast>,
+                ) {
+                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_fn(cx, fn_kind, body));
+                }
 
                 $crate::interface::LintCrateBindings {
                     set_ast_context,
+                    clear_ast_context,
                     info,
                     check_item,
                     check_field,
                     check_variant,
+                    check_generic_param,
                     check_body,
                     check_stmt,
                     check_expr,
+                    check_pat,
+                    check_fn,
                 }
             }
         }
@@ -11,6 +11,11 @@
 
 pub static MARKER_API_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The version of rustc, which was used to compile this crate. Lint crates
+/// have to be compiled with the same version as the driver.
+#[doc(hidden)]
+pub static MARKER_RUSTC_VERSION: &str = env!("MARKER_RUSTC_VERSION");
+
 pub mod ast;
 pub mod context;
 pub mod diagnostic;
@@ -34,9 +39,25 @@ pub trait LintPass {
     fn check_item<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _item: ast::item::ItemKind<'ast>) {}
     fn check_field<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _field: &'ast ast::item::Field<'ast>) {}
     fn check_variant<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _variant: &'ast ast::item::EnumVariant<'ast>) {}
+    fn check_generic_param<'ast>(
+        &mut self,
+        _cx: &'ast AstContext<'ast>,
+        _param: &'ast ast::generic::SynGenericParamKind<'ast>,
+    ) {
+    }
     fn check_body<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _body: &'ast ast::item::Body<'ast>) {}
     fn check_stmt<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _stmt: ast::stmt::StmtKind<'ast>) {}
     fn check_expr<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _expr: ast::expr::ExprKind<'ast>) {}
+    fn check_pat<'ast>(&mut self, _cx: &'ast AstContext<'ast>, _pat: ast::pat::PatKind<'ast>) {}
+    /// This is called for every function, method and closure with a body, in
+    /// addition to [`LintPass::check_item`] and [`LintPass::check_expr`].
+    fn check_fn<'ast>(
+        &mut self,
+        _cx: &'ast AstContext<'ast>,
+        _fn_kind: ast::item::FnKind<'ast>,
+        _body: &'ast ast::item::Body<'ast>,
+    ) {
+    }
 }
 
 pub(crate) mod private {
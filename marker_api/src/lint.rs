@@ -1,3 +1,5 @@
+use crate::ffi::FfiOption;
+
 #[repr(C)]
 #[derive(Debug, PartialEq, Eq, Hash)]
 // This sadly cannot be marked as #[non_exhaustive] as the struct construction
@@ -35,6 +37,12 @@ pub struct Lint {
     ///
     /// See [`MacroReport`] for the possible levels.
     pub report_in_macro: MacroReport,
+
+    /// The group this lint belongs to, if any.
+    ///
+    /// The level of all lints in a group can be set at once, by using the
+    /// name of the group in lint attributes.
+    pub group: FfiOption<&'static LintGroup>,
     // FIXME: We might want to add more fields. This should be possible as this
     // struct is always constructed by a macro controlled by marker. These are some
     // additional fields used  in rustc:
@@ -44,6 +52,24 @@ pub struct Lint {
     // * pub crate_level_only: bool,
 }
 
+/// A group of related lints. Lint attributes and command-line arguments can use
+/// the name of the group, to set the level of all lints in this group at once.
+///
+/// Lints are added to a group with the `group` argument of the [`declare_lint!`]
+/// macro. A group is declared with the [`declare_lint_group!`] macro.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct LintGroup {
+    /// A string identifier for the group.
+    ///
+    /// This identifies the group in attributes and in command-line arguments.
+    /// Like the name of lints, it's always lowercase in those contexts.
+    pub name: &'static str,
+
+    /// Description of the group and the lints it contains.
+    pub explanation: &'static str,
+}
+
 /// FIXME(xFrednet): These settings should be working now, but are still limited
 /// due to the limited [`Span`](crate::ast::Span) implementation. Ideally, I would
 /// also like more options, like a `Local` variant that only lints in local marcos.
@@ -59,9 +85,25 @@ pub enum MacroReport {
     No,
     /// Report in local and external macros.
     All,
+    /// Report in macros defined in the current crate, with an additional note
+    /// pointing to the macro call. Lints in external macros are not reported.
+    YesWithMacroNote,
 }
 
 /// Setting for how to handle a lint.
+///
+/// Levels are ordered by their severity, from [`Allow`](Self::Allow) to
+/// [`Forbid`](Self::Forbid). This allows the comparison of levels, for example,
+/// to select the most restrictive one with [`Ord::max`]:
+///
+/// ```
+/// # use marker_api::lint::Level;
+/// assert!(Level::Allow < Level::Warn);
+/// assert!(Level::Warn < Level::ForceWarn);
+/// assert!(Level::Deny < Level::Forbid);
+/// assert_eq!(Level::Warn.max(Level::Deny), Level::Deny);
+/// assert_eq!(Level::Warn.min(Level::Allow), Level::Allow);
+/// ```
 #[repr(C)]
 #[non_exhaustive]
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
@@ -76,6 +118,11 @@ pub enum Level {
     /// to the user but used internally. This can for instance happen for lint
     /// expectations (RFC 2383).
     Warn,
+    /// The `force-warn` level will produce a warning, like [`Warn`](Self::Warn),
+    /// but it can't be overridden by lint attributes, like `#[allow]`.
+    ///
+    /// This level is usually set with the `--force-warn` command-line argument.
+    ForceWarn,
     /// The `deny` level will produce an error and stop further execution after the lint
     /// pass is complete.
     Deny,
@@ -117,6 +164,23 @@ pub enum Level {
 ///     Warn,
 /// }
 /// ```
+///
+/// A lint can be added to a group, declared with [`declare_lint_group!`], by
+/// passing the group with the `group` argument after the level:
+///
+/// ```
+/// # marker_api::declare_lint_group!{
+/// #     /// Lints for uitests.
+/// #     UITESTS,
+/// # }
+/// marker_api::declare_lint!{
+///     /// # What it does
+///     /// Here you can describe what your lint does.
+///     ITEM_WITH_TEST_NAME,
+///     Warn,
+///     group: UITESTS,
+/// }
+/// ```
 #[macro_export]
 macro_rules! declare_lint {
     (
@@ -135,7 +199,54 @@ macro_rules! declare_lint {
         $(#[doc = $doc:literal])+
         $NAME: ident,
         $LEVEL: ident,
+        group: $GROUP: expr $(,)?
+    ) => {
+        $crate::declare_lint!{
+            $(#[doc = $doc])+
+            $NAME,
+            $LEVEL,
+            group: $GROUP,
+            $crate::lint::MacroReport::No,
+        }
+    };
+    (
+        $(#[doc = $doc:literal])+
+        $NAME: ident,
+        $LEVEL: ident,
+        group: $GROUP: expr,
         $REPORT_IN_MACRO: expr $(,)?
+    ) => {
+        $crate::declare_lint!{
+            @internal
+            $(#[doc = $doc])+
+            $NAME,
+            $LEVEL,
+            $REPORT_IN_MACRO,
+            $crate::ffi::FfiOption::Some($GROUP),
+        }
+    };
+    (
+        $(#[doc = $doc:literal])+
+        $NAME: ident,
+        $LEVEL: ident,
+        $REPORT_IN_MACRO: expr $(,)?
+    ) => {
+        $crate::declare_lint!{
+            @internal
+            $(#[doc = $doc])+
+            $NAME,
+            $LEVEL,
+            $REPORT_IN_MACRO,
+            $crate::ffi::FfiOption::None,
+        }
+    };
+    (
+        @internal
+        $(#[doc = $doc:literal])+
+        $NAME: ident,
+        $LEVEL: ident,
+        $REPORT_IN_MACRO: expr,
+        $GROUP: expr $(,)?
     ) => {
         $(#[doc = $doc])+
         pub static $NAME: &$crate::lint::Lint = &$crate::lint::Lint {
@@ -143,6 +254,31 @@ macro_rules! declare_lint {
             default_level: $crate::lint::Level::$LEVEL,
             explanation: concat!($($doc, '\n',)*),
             report_in_macro: $REPORT_IN_MACRO,
+            group: $GROUP,
+        };
+    };
+}
+
+/// This macro creates a new lint group. Lints can be added to the group with the
+/// `group` argument of the [`declare_lint!`] macro. The doc comment of the group
+/// should describe the lints it contains.
+///
+/// ```
+/// marker_api::declare_lint_group!{
+///     /// Lints which check the naming of items.
+///     NAMING,
+/// }
+/// ```
+#[macro_export]
+macro_rules! declare_lint_group {
+    (
+        $(#[doc = $doc:literal])+
+        $NAME: ident $(,)?
+    ) => {
+        $(#[doc = $doc])+
+        pub static $NAME: &$crate::lint::LintGroup = &$crate::lint::LintGroup {
+            name: concat!("marker::", stringify!($NAME)),
+            explanation: concat!($($doc, '\n',)*),
         };
     };
 }
@@ -3,17 +3,26 @@ use std::cell::{OnceCell, RefCell};
 use marker_adapter::context::{DriverContext, DriverContextWrapper};
 use marker_api::{
     ast::{
-        item::{Body, ItemKind},
-        BodyId, ExprId, ItemId, Span, SpanId, SymbolId, TyDefId,
+        expr::ExprKind,
+        item::{Body, ItemKind, VisibilityKind},
+        ty::{SemAdtTy, SemTyKind},
+        Attr, BodyId, ConstValue, ExprId, FilePos, ItemId, LineBounds, MacroInfo, MacroOrigin, Span, SpanId,
+        SpanSource, SpanSrcId, SymbolId, TyDefId,
     },
-    context::AstContext,
+    context::{AstContext, ItemDeprecation, KnownTrait},
     diagnostic::{Diagnostic, EmissionNode},
     lint::{Level, Lint},
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
+use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::LintStore;
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{Const, GenericArg, ParamEnv, TyCtxt, TyKind};
+use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt as _;
+use rustc_trait_selection::{
+    infer::InferCtxtExt,
+    traits::{Obligation, ObligationCause},
+};
 
 use crate::conversion::{marker::MarkerConverter, rustc::RustcConverter};
 
@@ -43,6 +52,9 @@ pub struct RustcContext<'ast, 'tcx> {
     /// which makes it safe to access afterwards.
     ast_cx: OnceCell<&'ast AstContext<'ast>>,
     resolved_ty_ids: RefCell<FxHashMap<&'ast str, &'ast [TyDefId]>>,
+    trait_impls: RefCell<FxHashMap<TyDefId, &'ast [ItemId]>>,
+    item_attrs: RefCell<FxHashMap<ItemId, &'ast [Attr<'ast>]>>,
+    all_items: OnceCell<&'ast [ItemId]>,
 }
 
 impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
@@ -56,6 +68,9 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
             rustc_converter: RustcConverter::new(rustc_cx, storage),
             ast_cx: OnceCell::new(),
             resolved_ty_ids: RefCell::default(),
+            trait_impls: RefCell::default(),
+            item_attrs: RefCell::default(),
+            all_items: OnceCell::new(),
         });
 
         // Create and link `AstContext`
@@ -71,6 +86,34 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
         // The `OnceCell` is filled in the new function and can never be not set.
         self.ast_cx.get().unwrap()
     }
+
+    /// Determines the visibility from the modifier written in the given span.
+    /// `None` is returned, if the modifier can't be read, for example, because
+    /// it comes from a macro.
+    fn declared_visibility(&self, def_id: hir::def_id::DefId, vis_span: rustc_span::Span) -> Option<VisibilityKind> {
+        if vis_span.is_empty() {
+            return Some(VisibilityKind::Private);
+        }
+
+        let snippet = self.rustc_cx.sess.source_map().span_to_snippet(vis_span).ok()?;
+        let modifier: String = snippet.chars().filter(|c| !c.is_whitespace()).collect();
+        match modifier.as_str() {
+            "pub" => Some(VisibilityKind::Public),
+            "pub(crate)" | "crate" => Some(VisibilityKind::PubCrate),
+            "pub(super)" => Some(VisibilityKind::PubSuper),
+            "pub(self)" => Some(VisibilityKind::Private),
+            _ if modifier.starts_with("pub(in") => match self.rustc_cx.visibility(def_id) {
+                rustc_middle::ty::Visibility::Restricted(module) if module.is_crate_root() => {
+                    Some(VisibilityKind::PubCrate)
+                },
+                rustc_middle::ty::Visibility::Restricted(module) => {
+                    Some(VisibilityKind::PubIn(self.marker_converter.to_item_id(module)))
+                },
+                rustc_middle::ty::Visibility::Public => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
@@ -84,9 +127,9 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         }
     }
 
-    fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>) {
+    fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>) -> bool {
         let Some(id) = self.rustc_converter.try_to_hir_id_from_emission_node(diag.node) else {
-            return;
+            return false;
         };
         let lint = self.rustc_converter.to_lint(diag.lint);
         self.rustc_cx.struct_span_lint_hir(
@@ -117,12 +160,27 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
                                 self.rustc_converter.to_applicability(*app),
                             );
                         },
+                        marker_api::diagnostic::DiagnosticPart::MultipartSuggestion { msg, parts, app } => {
+                            builder.multipart_suggestion(
+                                msg.get().to_string(),
+                                parts
+                                    .get()
+                                    .iter()
+                                    .map(|part| (self.rustc_converter.to_span(part.span), part.sugg.get().to_string()))
+                                    .collect(),
+                                self.rustc_converter.to_applicability(*app),
+                            );
+                        },
+                        marker_api::diagnostic::DiagnosticPart::Label { span, label } => {
+                            builder.span_label(self.rustc_converter.to_span(span), label.get().to_string());
+                        },
                         _ => unreachable!(),
                     }
                 }
                 builder
             },
         );
+        true
     }
 
     fn item(&'ast self, api_id: ItemId) -> Option<ItemKind<'ast>> {
@@ -131,9 +189,172 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         self.marker_converter.to_item(rust_item)
     }
 
-    fn body(&'ast self, id: BodyId) -> &'ast Body<'ast> {
-        let rustc_body = self.rustc_cx.hir().body(self.rustc_converter.to_body_id(id));
-        self.marker_converter.to_body(rustc_body)
+    fn parent_item(&'ast self, api_id: ItemId) -> Option<ItemId> {
+        let def_id = self.rustc_converter.to_def_id(api_id).as_local()?;
+        let map = self.rustc_cx.hir();
+        let parent = map.get_parent_item(map.local_def_id_to_hir_id(def_id));
+        (parent != hir::CRATE_OWNER_ID).then(|| self.marker_converter.to_item_id(parent.to_def_id()))
+    }
+
+    fn all_items(&'ast self) -> &'ast [ItemId] {
+        self.all_items.get_or_init(|| {
+            // The `DefIndex` of local items follows the module tree in preorder
+            let mut items: Vec<_> = self.rustc_cx.hir_crate_items(()).items().collect();
+            items.sort_by_key(|id| id.owner_id.def_id.local_def_index);
+            self.storage.alloc_slice(
+                items
+                    .into_iter()
+                    .map(|id| self.marker_converter.to_item_id(id.owner_id.to_def_id())),
+            )
+        })
+    }
+
+    fn macro_info(&'ast self, src_id: SpanSrcId) -> Option<&'ast MacroInfo<'ast>> {
+        let expn_data = self.rustc_converter.to_syntax_context(src_id).outer_expn_data();
+        let rustc_span::ExpnKind::Macro(_, name) = expn_data.kind else {
+            return None;
+        };
+        let def_site = (!expn_data.def_site.is_dummy()).then(|| self.marker_converter.to_span_id(expn_data.def_site));
+        Some(self.storage.alloc(MacroInfo::new(
+            self.marker_converter.to_symbol_id(name),
+            self.marker_converter.to_span_id(expn_data.call_site),
+            def_site,
+        )))
+    }
+
+    fn item_visibility(&'ast self, api_id: ItemId) -> VisibilityKind {
+        let tcx = self.rustc_cx;
+        let def_id = self.rustc_converter.to_def_id(api_id);
+        let containing_module = |mut id: hir::def_id::DefId| loop {
+            id = tcx.opt_parent(id)?;
+            if matches!(tcx.def_kind(id), hir::def::DefKind::Mod) {
+                return Some(id);
+            }
+        };
+
+        // The declared modifier is used for local items, if it's available
+        let vis_span = def_id
+            .as_local()
+            .and_then(|local_id| match tcx.hir().find_by_def_id(local_id)? {
+                hir::Node::Item(item) => Some(item.vis_span),
+                hir::Node::ImplItem(item) => Some(item.vis_span),
+                hir::Node::ForeignItem(item) => Some(item.vis_span),
+                hir::Node::Field(field) => Some(field.vis_span),
+                _ => None,
+            });
+        if let Some(kind) = vis_span.and_then(|vis_span| self.declared_visibility(def_id, vis_span)) {
+            return kind;
+        }
+
+        // Otherwise, the visibility is determined by the module, in which
+        // the item is visible
+        match tcx.visibility(def_id) {
+            rustc_middle::ty::Visibility::Public => VisibilityKind::Public,
+            rustc_middle::ty::Visibility::Restricted(module) => {
+                let current = containing_module(def_id);
+                if Some(module) == current {
+                    VisibilityKind::Private
+                } else if module.is_crate_root() {
+                    VisibilityKind::PubCrate
+                } else if Some(module) == current.and_then(containing_module) {
+                    VisibilityKind::PubSuper
+                } else {
+                    VisibilityKind::PubIn(self.marker_converter.to_item_id(module))
+                }
+            },
+        }
+    }
+
+    fn definition_span(&'ast self, api_id: ItemId) -> Option<SpanId> {
+        let span = self.rustc_cx.def_span(self.rustc_converter.to_def_id(api_id));
+        (!span.is_dummy()).then(|| self.marker_converter.to_span_id(span))
+    }
+
+    fn resolve_item_path(&'ast self, api_id: ItemId) -> Option<&'ast str> {
+        use hir::definitions::DefPathData;
+
+        let tcx = self.rustc_cx;
+        let def_id = self.rustc_converter.to_def_id(api_id);
+        let def_path = tcx.def_path(def_id);
+        let (last, parents) = def_path.data.split_last()?;
+
+        // Only modules, types and traits can be part of a stable path
+        let mut path = tcx.crate_name(def_id.krate).to_string();
+        for segment in parents {
+            let DefPathData::TypeNs(name) = segment.data else {
+                return None;
+            };
+            path.push_str("::");
+            path.push_str(name.as_str());
+        }
+        match last.data {
+            DefPathData::TypeNs(name) | DefPathData::ValueNs(name) | DefPathData::MacroNs(name)
+                if name != rustc_span::symbol::kw::Underscore =>
+            {
+                path.push_str("::");
+                path.push_str(name.as_str());
+            },
+            _ => return None,
+        }
+
+        Some(self.storage.alloc_str(&path))
+    }
+
+    fn body(&'ast self, id: BodyId) -> Result<&'ast Body<'ast>, &'ast str> {
+        let rustc_body_id = self.rustc_converter.to_body_id(id);
+        let rustc_body = self
+            .rustc_cx
+            .hir_owner_nodes(rustc_body_id.hir_id.owner)
+            .as_owner()
+            .and_then(|nodes| nodes.bodies.get(&rustc_body_id.hir_id.local_id));
+        match rustc_body {
+            Some(rustc_body) => Ok(self.marker_converter.to_body(rustc_body)),
+            None => Err(self.storage.alloc_str(&format!("the body `{id:?}` is unknown"))),
+        }
+    }
+
+    fn expr(&'ast self, id: ExprId) -> Option<ExprKind<'ast>> {
+        self.marker_converter.expr(id)
+    }
+
+    fn callee_of(&'ast self, api_id: ExprId) -> Option<ItemId> {
+        let hir_id = self.rustc_converter.to_hir_id(api_id);
+        self.marker_converter.callee_of(hir_id)
+    }
+
+    fn resolve_const_value(&'ast self, api_id: ExprId) -> Option<&'ast ConstValue<'ast>> {
+        let tcx = self.rustc_cx;
+        let hir_id = self.rustc_converter.to_hir_id(api_id);
+        let owner = tcx.hir().enclosing_body_owner(hir_id);
+        if !matches!(tcx.def_kind(owner), hir::def::DefKind::AnonConst) {
+            return None;
+        }
+        let konst = Const::from_anon_const(tcx, owner);
+        let value = self.marker_converter.to_const_value(konst)?;
+        Some(self.storage.alloc(value))
+    }
+
+    fn item_deprecation(&'ast self, api_id: ItemId) -> Option<ItemDeprecation<'ast>> {
+        let def_id = self.rustc_converter.to_def_id(api_id);
+        let deprecation = self.rustc_cx.lookup_deprecation(def_id)?;
+        Some(ItemDeprecation {
+            since: deprecation.since.map(|sym| rustc_sym_str(sym).into()).into(),
+            note: deprecation.note.map(|sym| rustc_sym_str(sym).into()).into(),
+        })
+    }
+
+    fn item_attrs(&'ast self, api_id: ItemId) -> &'ast [Attr<'ast>] {
+        // Caching
+        if let Some(attrs) = self.item_attrs.borrow().get(&api_id) {
+            return attrs;
+        }
+
+        let def_id = self.rustc_converter.to_def_id(api_id);
+        let attrs = self
+            .marker_converter
+            .to_attrs(self.rustc_cx.get_attrs_unchecked(def_id));
+        self.item_attrs.borrow_mut().insert(api_id, attrs);
+        attrs
     }
 
     fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId] {
@@ -195,6 +416,7 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
                         | hir::def::DefKind::Union
                         | hir::def::DefKind::Enum
                         | hir::def::DefKind::Trait
+                        | hir::def::DefKind::TraitAlias
                         | hir::def::DefKind::TyAlias
                 )
             })
@@ -209,11 +431,108 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         ids
     }
 
-    fn expr_ty(&'ast self, expr: ExprId) -> marker_api::ast::ty::SemTyKind<'ast> {
+    fn type_implements_trait(&'ast self, ty: SemTyKind<'ast>, trait_id: TyDefId, args: &[SemTyKind<'ast>]) -> bool {
+        let tcx = self.rustc_cx;
+        let trait_def_id = self.rustc_converter.to_def_id(trait_id);
+        let def_kind = tcx.def_kind(trait_def_id);
+        if !matches!(def_kind, hir::def::DefKind::Trait | hir::def::DefKind::TraitAlias) {
+            return false;
+        }
+        // The `Self` type is part of the generic parameters
+        if tcx.generics_of(trait_def_id).count() != args.len() + 1 {
+            return false;
+        }
+
+        let Some(rustc_tys) = std::iter::once(ty)
+            .chain(args.iter().copied())
+            .map(|ty| self.rustc_converter.to_ty(ty))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+
+        let infcx = tcx.infer_ctxt().build();
+        if matches!(def_kind, hir::def::DefKind::TraitAlias) {
+            // Trait aliases are satisfied, if all their bounds hold
+            let substs = tcx.mk_substs_from_iter(rustc_tys.into_iter().map(GenericArg::from));
+            return tcx
+                .predicates_of(trait_def_id)
+                .instantiate(tcx, substs)
+                .predicates
+                .into_iter()
+                .all(|predicate| {
+                    let obligation = Obligation::new(tcx, ObligationCause::dummy(), ParamEnv::reveal_all(), predicate);
+                    infcx.predicate_must_hold_modulo_regions(&obligation)
+                });
+        }
+        infcx
+            .type_implements_trait(trait_def_id, rustc_tys, ParamEnv::reveal_all())
+            .must_apply_modulo_regions()
+    }
+
+    fn check_trait_impl(&'ast self, ty: SemTyKind<'ast>, known_trait: KnownTrait) -> bool {
+        let tcx = self.rustc_cx;
+        let trait_def_id = match known_trait {
+            KnownTrait::Copy => tcx.lang_items().copy_trait(),
+            KnownTrait::Send => tcx.get_diagnostic_item(rustc_span::sym::Send),
+            KnownTrait::Sync => tcx.lang_items().sync_trait(),
+            _ => unreachable!(),
+        };
+        let (Some(trait_def_id), Some(rustc_ty)) = (trait_def_id, self.rustc_converter.to_ty(ty)) else {
+            return false;
+        };
+
+        let infcx = tcx.infer_ctxt().build();
+        infcx
+            .type_implements_trait(trait_def_id, [rustc_ty], ParamEnv::reveal_all())
+            .must_apply_modulo_regions()
+    }
+
+    fn trait_impls(&'ast self, trait_id: TyDefId) -> &'ast [ItemId] {
+        // Caching
+        if let Some(ids) = self.trait_impls.borrow().get(&trait_id) {
+            return ids;
+        }
+
+        let tcx = self.rustc_cx;
+        let trait_def_id = self.rustc_converter.to_def_id(trait_id);
+        let ids: Vec<_> = if matches!(tcx.def_kind(trait_def_id), hir::def::DefKind::Trait) {
+            tcx.all_impls(trait_def_id)
+                .filter(|def_id| def_id.is_local())
+                .map(|def_id| self.marker_converter.to_item_id(def_id))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        // Allocation and caching
+        let ids = self.storage.alloc_slice(ids);
+        self.trait_impls.borrow_mut().insert(trait_id, ids);
+        ids
+    }
+
+    fn expr_ty(&'ast self, expr: ExprId) -> SemTyKind<'ast> {
         let hir_id = self.rustc_converter.to_hir_id(expr);
         self.marker_converter.expr_ty(hir_id)
     }
 
+    fn field_ty_of(&'ast self, adt: &'ast SemAdtTy<'ast>, name: &str) -> Option<SemTyKind<'ast>> {
+        let rustc_ty = self.rustc_converter.to_ty(SemTyKind::Adt(adt))?;
+        let TyKind::Adt(adt_def, substs) = rustc_ty.kind() else {
+            unreachable!("ADTs are always converted to ADTs")
+        };
+        if adt_def.is_enum() {
+            return None;
+        }
+
+        let field = adt_def
+            .non_enum_variant()
+            .fields
+            .iter()
+            .find(|field| field.name.as_str() == name)?;
+        Some(self.marker_converter.to_sem_ty(field.ty(self.rustc_cx, substs)))
+    }
+
     fn span(&'ast self, span_id: SpanId) -> &'ast Span<'ast> {
         let rustc_span = self.rustc_converter.to_span_from_id(span_id);
         self.storage.alloc(self.marker_converter.to_span(rustc_span))
@@ -225,21 +544,57 @@ impl<'ast, 'tcx: 'ast> DriverContext<'ast> for RustcContext<'ast, 'tcx> {
         Some(self.storage.alloc_str(&snippet))
     }
 
-    fn symbol_str(&'ast self, api_id: SymbolId) -> &'ast str {
-        let sym = self.rustc_converter.to_symbol(api_id);
-        // The lifetime is fake, as documented in [`rustc_span::Span::as_str()`].
-        // It'll definitely live longer than the `'ast` lifetime, it's transmuted to.
-        let rustc_str: &str = sym.as_str();
-        // # Safety
-        // `'ast` is shorter than `'tcx` or any rustc lifetime. This transmute
-        // in combination with the comment above is therefore safe.
-        let api_str: &'ast str = unsafe { std::mem::transmute(rustc_str) };
-        api_str
+    fn span_macro_origin(&'ast self, api_span: &Span<'ast>) -> Option<MacroOrigin> {
+        let rustc_span = self.rustc_converter.to_span(api_span);
+        let expn_data = rustc_span.ctxt().outer_expn_data();
+        let rustc_span::ExpnKind::Macro(_, name) = expn_data.kind else {
+            return None;
+        };
+        let call_site = rustc_span.source_callee()?.call_site;
+        Some(MacroOrigin {
+            call_site: self.marker_converter.to_span_id(call_site),
+            name: self.marker_converter.to_symbol_id(name),
+            is_local: expn_data.macro_def_id.is_some_and(hir::def_id::DefId::is_local),
+        })
     }
 
-    fn resolve_method_target(&'ast self, _id: ExprId) -> ItemId {
-        todo!()
+    fn span_pos_to_file_pos(&'ast self, file: &SpanSource<'ast>, pos: usize) -> Option<FilePos> {
+        let (_, src_info) = self.storage.get_span_src_info(file)?;
+        #[expect(clippy::cast_possible_truncation, reason = "`u32` is set by rustc and will be fine")]
+        let rustc_pos = rustc_span::BytePos((pos + src_info.rustc_start_offset) as u32);
+        let loc = self.rustc_cx.sess.source_map().lookup_char_pos(rustc_pos);
+        Some(FilePos {
+            line: u32::try_from(loc.line).ok()?,
+            col: u32::try_from(loc.col.0 + 1).ok()?,
+        })
     }
+
+    fn span_pos_to_line_bounds(&'ast self, file: &SpanSource<'ast>, pos: usize) -> Option<LineBounds> {
+        let (_, src_info) = self.storage.get_span_src_info(file)?;
+        #[expect(clippy::cast_possible_truncation, reason = "`u32` is set by rustc and will be fine")]
+        let rustc_pos = rustc_span::BytePos((pos + src_info.rustc_start_offset) as u32);
+        let file_and_line = self.rustc_cx.sess.source_map().lookup_line(rustc_pos).ok()?;
+        let bounds = file_and_line.sf.line_bounds(file_and_line.line);
+        Some(LineBounds {
+            start: (bounds.start.0 as usize).checked_sub(src_info.rustc_start_offset)?,
+            end: (bounds.end.0 as usize).checked_sub(src_info.rustc_start_offset)?,
+        })
+    }
+
+    fn symbol_str(&'ast self, api_id: SymbolId) -> &'ast str {
+        rustc_sym_str(self.rustc_converter.to_symbol(api_id))
+    }
+}
+
+fn rustc_sym_str<'ast>(sym: rustc_span::Symbol) -> &'ast str {
+    // The lifetime is fake, as documented in [`rustc_span::Span::as_str()`].
+    // It'll definitely live longer than the `'ast` lifetime, it's transmuted to.
+    let rustc_str: &str = sym.as_str();
+    // # Safety
+    // `'ast` is shorter than `'tcx` or any rustc lifetime. This transmute
+    // in combination with the comment above is therefore safe.
+    let api_str: &'ast str = unsafe { std::mem::transmute(rustc_str) };
+    api_str
 }
 
 fn select_children_with_name(
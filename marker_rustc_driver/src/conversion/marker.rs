@@ -19,7 +19,7 @@ use marker_api::{
         expr::ExprKind,
         item::{Body, ItemKind},
         ty::SemTyKind,
-        BodyId, Crate, ExprId, ItemId, Span, SymbolId, TyDefId,
+        Attr, BodyId, ConstValue, Crate, ExprId, ItemId, Span, SpanId, SymbolId, TyDefId,
     },
     lint::Level,
 };
@@ -71,11 +71,45 @@ impl<'ast, 'tcx> MarkerConverter<'ast, 'tcx> {
         })
     }
 
+    pub fn callee_of(&self, id: hir::HirId) -> Option<ItemId> {
+        let hir::Node::Expr(expr) = self.inner.rustc_cx.hir().get(id) else {
+            return None;
+        };
+        self.with_body(id, |inner| {
+            let ty_check = inner.rustc_ty_check();
+            let def_id = match expr.kind {
+                hir::ExprKind::MethodCall(..) => ty_check.type_dependent_def_id(id)?,
+                hir::ExprKind::Call(operand, _) => match ty_check.node_type(operand.hir_id).kind() {
+                    rustc_middle::ty::FnDef(def_id, _) => *def_id,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            // Constructors of tuple structs and variants are not items
+            if matches!(inner.rustc_cx.def_kind(def_id), hir::def::DefKind::Ctor(..)) {
+                return None;
+            }
+            Some(inner.to_item_id(def_id))
+        })
+    }
+
+    /// Expressions are only converted as part of their body. This therefore
+    /// only returns expressions from the cache and never triggers a conversion.
+    pub fn expr(&self, id: ExprId) -> Option<ExprKind<'ast>> {
+        self.inner.exprs.borrow().get(&id).copied()
+    }
+
     forward_to_inner!(pub fn to_lint_level(&self, level: rustc_lint::Level) -> Level);
     forward_to_inner!(pub fn to_item(&self, rustc_item: &'tcx hir::Item<'tcx>) -> Option<ItemKind<'ast>>);
     forward_to_inner!(pub fn to_body(&self, body: &hir::Body<'tcx>) -> &'ast Body<'ast>);
+    forward_to_inner!(pub fn to_item_id(&self, id: hir::def_id::DefId) -> ItemId);
+    forward_to_inner!(pub fn to_attrs(&self, attrs: &[rustc_ast::Attribute]) -> &'ast [Attr<'ast>]);
     forward_to_inner!(pub fn to_ty_def_id(&self, id: hir::def_id::DefId) -> TyDefId);
+    forward_to_inner!(pub fn to_const_value(&self, konst: rustc_middle::ty::Const<'tcx>) -> Option<ConstValue<'ast>>);
+    forward_to_inner!(pub fn to_sem_ty(&self, rustc_ty: rustc_middle::ty::Ty<'tcx>) -> SemTyKind<'ast>);
     forward_to_inner!(pub fn to_span(&self, rustc_span: rustc_span::Span) -> Span<'ast>);
+    forward_to_inner!(pub fn to_span_id(&self, rustc_span: rustc_span::Span) -> SpanId);
+    forward_to_inner!(pub fn to_symbol_id(&self, sym: rustc_span::Symbol) -> SymbolId);
     forward_to_inner!(pub fn to_crate(
         &self,
         rustc_crate_id: hir::def_id::CrateNum,
@@ -1,11 +1,14 @@
+use std::borrow::Cow;
 use std::mem::{size_of, transmute};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use marker_api::ast::generic::SynGenericArgs;
 use marker_api::ast::ty::SynTyKind;
 use marker_api::ast::{
-    Abi, AstPath, AstPathSegment, AstPathTarget, AstQPath, BodyId, Constness, CrateId, ExprId, FieldId, GenericId,
-    Ident, ItemId, LetStmtId, Mutability, Safety, Span, SpanId, SpanSource, SpanSrcId, SymbolId, Syncness, TraitRef,
-    TyDefId, VarId, VariantId,
+    Abi, AstPath, AstPathSegment, AstPathTarget, AstQPath, Attr, BodyId, Constness, CrateId, ExprId, FieldId,
+    GenericId, Ident, ItemId, LetStmtId, Mutability, Safety, Span, SpanId, SpanSource, SpanSrcId, SymbolId, Syncness,
+    TraitRef, TyDefId, VarId, VariantId,
 };
 use marker_api::lint::Level;
 use rustc_hir as hir;
@@ -144,7 +147,8 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     pub fn to_lint_level(&self, level: rustc_lint::Level) -> Level {
         match level {
             rustc_lint::Level::Allow => Level::Allow,
-            rustc_lint::Level::Warn | rustc_lint::Level::ForceWarn(_) | rustc_lint::Level::Expect(_) => Level::Warn,
+            rustc_lint::Level::Warn | rustc_lint::Level::Expect(_) => Level::Warn,
+            rustc_lint::Level::ForceWarn(_) => Level::ForceWarn,
             rustc_lint::Level::Deny => Level::Deny,
             rustc_lint::Level::Forbid => Level::Forbid,
         }
@@ -155,6 +159,42 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         Ident::new(self.to_symbol_id(ident.name), self.to_span_id(ident.span))
     }
 
+    /// Converts the outer attributes of the given slice. Inner attributes are
+    /// skipped.
+    #[must_use]
+    pub fn to_attrs(&self, attrs: &[rustc_ast::Attribute]) -> &'ast [Attr<'ast>] {
+        let attrs: Vec<_> = attrs
+            .iter()
+            .filter(|attr| attr.style == rustc_ast::AttrStyle::Outer)
+            .map(|attr| self.to_attr(attr))
+            .collect();
+        self.alloc_slice(attrs)
+    }
+
+    fn to_attr(&self, attr: &rustc_ast::Attribute) -> Attr<'ast> {
+        let path = match &attr.kind {
+            rustc_ast::AttrKind::Normal(normal) => self.alloc_slice(
+                normal
+                    .item
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| self.to_ident(segment.ident)),
+            ),
+            rustc_ast::AttrKind::DocComment(..) => self.alloc_slice([Ident::new(
+                self.to_symbol_id(rustc_span::sym::doc),
+                self.to_span_id(attr.span),
+            )]),
+        };
+        let value = if attr.is_doc_comment() {
+            attr.doc_str()
+        } else {
+            attr.value_str()
+        };
+        let value = value.map(|value| self.storage.alloc_str(value.as_str()).into());
+        Attr::new(path, value, attr.is_doc_comment(), self.to_span_id(attr.span))
+    }
+
     #[must_use]
     pub fn to_mutability(&self, mutability: rustc_ast::Mutability) -> Mutability {
         match mutability {
@@ -189,7 +229,18 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     pub fn to_abi(&self, rust_abi: rustc_target::spec::abi::Abi) -> Abi {
         match rust_abi {
             rustc_target::spec::abi::Abi::Rust => Abi::Default,
-            rustc_target::spec::abi::Abi::C { .. } => Abi::C,
+            rustc_target::spec::abi::Abi::C { unwind: false } => Abi::C,
+            rustc_target::spec::abi::Abi::C { unwind: true } => Abi::CUnwind,
+            rustc_target::spec::abi::Abi::System { unwind: false } => Abi::System,
+            rustc_target::spec::abi::Abi::System { unwind: true } => Abi::SystemUnwind,
+            // The unwind variants of these ABIs are unstable and mapped to
+            // their base ABI.
+            rustc_target::spec::abi::Abi::Cdecl { .. } => Abi::Cdecl,
+            rustc_target::spec::abi::Abi::Stdcall { .. } => Abi::Stdcall,
+            rustc_target::spec::abi::Abi::Fastcall { .. } => Abi::Fastcall,
+            rustc_target::spec::abi::Abi::Aapcs { .. } => Abi::Aapcs,
+            rustc_target::spec::abi::Abi::Win64 { .. } => Abi::Win64,
+            rustc_target::spec::abi::Abi::SysV64 { .. } => Abi::SysV64,
             _ => Abi::Other,
         }
     }
@@ -411,7 +462,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 | rustc_span::RealFileName::Remapped { virtual_name: path, .. },
             ) = &rustc_src.name
             {
-                path.to_string_lossy().to_string()
+                crate_relative_path(path).to_string_lossy().to_string()
             } else {
                 unreachable!("spans which don't come from from expansion always belong to a file")
             };
@@ -468,3 +519,22 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
     }
 }
+
+/// Returns the given path relative to the root of the compiled crate, if the
+/// path is located inside the crate directory. Cargo provides the crate root
+/// via the `CARGO_MANIFEST_DIR` environment value.
+fn crate_relative_path(path: &Path) -> Cow<'_, Path> {
+    static CRATE_ROOT: OnceLock<Option<(PathBuf, PathBuf)>> = OnceLock::new();
+    let Some((crate_root, current_dir)) = CRATE_ROOT.get_or_init(|| {
+        let crate_root = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR")?);
+        Some((crate_root, std::env::current_dir().ok()?))
+    }) else {
+        return Cow::Borrowed(path);
+    };
+
+    let abs_path = current_dir.join(path);
+    match abs_path.strip_prefix(crate_root) {
+        Ok(relative) => Cow::Owned(relative.to_path_buf()),
+        Err(_) => Cow::Borrowed(path),
+    }
+}
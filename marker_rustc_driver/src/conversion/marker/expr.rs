@@ -8,13 +8,15 @@ use marker_api::{
             QuestionMarkExpr, RangeExpr, RefExpr, ReturnExpr, StrLitData, StrLitExpr, TupleExpr, UnaryOpExpr,
             UnaryOpKind, UnstableExpr, WhileExpr,
         },
+        generic::SemGenericArgKind,
         pat::PatKind,
-        Ident, Safety, Syncness,
+        Constness, Ident, Safety, Syncness,
     },
     CtorBlocker,
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
+use rustc_span::Pos;
 use std::str::FromStr;
 
 use super::MarkerConverterInner;
@@ -28,8 +30,14 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
 
         let data = CommonExprData::new(id, self.to_span_id(block.span));
-        let expr =
-            ExprKind::Block(self.alloc(self.to_block_expr(data, block, None, Syncness::Sync, CaptureKind::Default)));
+        let expr = ExprKind::Block(self.alloc(self.to_block_expr(
+            data,
+            block,
+            None,
+            Syncness::Sync,
+            Constness::NotConst,
+            CaptureKind::Default,
+        )));
 
         self.exprs.borrow_mut().insert(id, expr);
         expr
@@ -82,6 +90,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         block,
                         *label,
                         Syncness::Sync,
+                        Constness::NotConst,
                         CaptureKind::Default,
                     )))
                 }
@@ -125,6 +134,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     self.to_expr(receiver),
                     self.to_path_segment(method),
                     self.to_exprs(args),
+                    self.to_method_turbofish(expr, method),
                 )
             })),
             hir::ExprKind::Path(
@@ -251,6 +261,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 hir::LoopSource::ForLoop => unreachable!("is desugared at a higher node level"),
             },
             hir::ExprKind::Closure(closure) => self.to_expr_from_closure(data, expr, closure),
+            hir::ExprKind::ConstBlock(const_block) => self.to_expr_from_const_block(data, const_block),
             hir::ExprKind::Cast(expr, ty) => {
                 ExprKind::As(self.alloc(AsExpr::new(data, self.to_expr(expr), self.to_syn_ty(ty))))
             },
@@ -282,6 +293,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         block: &hir::Block<'tcx>,
         label: Option<rustc_ast::Label>,
         syncness: Syncness,
+        constness: Constness,
         capture_kind: CaptureKind,
     ) -> BlockExpr<'ast> {
         let stmts: Vec<_> = block.stmts.iter().filter_map(|stmt| self.to_stmt(stmt)).collect();
@@ -297,24 +309,50 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             label.map(|label| self.to_ident(label.ident)),
             safety,
             syncness,
+            constness,
             capture_kind,
         )
     }
 
+    #[must_use]
+    fn to_expr_from_const_block(&self, data: CommonExprData<'ast>, const_block: &hir::ConstBlock) -> ExprKind<'ast> {
+        let body = self.rustc_cx.hir().body(const_block.body);
+        if let hir::ExprKind::Block(block, None) = body.value.kind {
+            let api_block_expr = self.with_body(const_block.body, || {
+                self.to_block_expr(
+                    data,
+                    block,
+                    None,
+                    Syncness::Sync,
+                    Constness::Const,
+                    CaptureKind::Default,
+                )
+            });
+            return ExprKind::Block(self.alloc(api_block_expr));
+        }
+        unreachable!("inline `const` blocks always have a block as their body")
+    }
+
     #[must_use]
     fn to_expr_from_lit_kind(&self, data: CommonExprData<'ast>, lit_kind: &rustc_ast::LitKind) -> ExprKind<'ast> {
         match &lit_kind {
             rustc_ast::LitKind::Str(sym, kind) => ExprKind::StrLit(self.alloc({
                 StrLitExpr::new(
                     data,
-                    matches!(kind, rustc_ast::StrStyle::Raw(_)),
+                    match kind {
+                        rustc_ast::StrStyle::Cooked => None,
+                        rustc_ast::StrStyle::Raw(hashes) => Some(*hashes),
+                    },
                     StrLitData::Sym(self.to_symbol_id(*sym)),
                 )
             })),
             rustc_ast::LitKind::ByteStr(bytes, kind) => ExprKind::StrLit(self.alloc({
                 StrLitExpr::new(
                     data,
-                    matches!(kind, rustc_ast::StrStyle::Raw(_)),
+                    match kind {
+                        rustc_ast::StrStyle::Cooked => None,
+                        rustc_ast::StrStyle::Raw(hashes) => Some(*hashes),
+                    },
                     StrLitData::Bytes(self.alloc_slice(bytes.iter().copied()).into()),
                 )
             })),
@@ -351,7 +389,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     rustc_ast::LitFloatType::Unsuffixed => None,
                 };
                 let value = f64::from_str(lit_sym.as_str()).expect("rustc should have validated the literal");
-                ExprKind::FloatLit(self.alloc(FloatLitExpr::new(data, value, suffix)))
+                ExprKind::FloatLit(self.alloc(FloatLitExpr::new(data, value, self.to_symbol_id(*lit_sym), suffix)))
             },
             rustc_ast::LitKind::Bool(value) => ExprKind::BoolLit(self.alloc(BoolLitExpr::new(data, *value))),
             rustc_ast::LitKind::Err => unreachable!("would have triggered a rustc error"),
@@ -398,19 +436,38 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 
     #[must_use]
     fn to_match_arm(&self, arm: &hir::Arm<'tcx>) -> MatchArm<'ast> {
-        let guard = match &arm.guard {
-            Some(hir::Guard::If(expr)) => Some(self.to_expr(expr)),
-            Some(hir::Guard::IfLet(lets)) => Some(self.to_let_expr(lets)),
-            None => None,
+        let (guard, guard_span) = match &arm.guard {
+            Some(hir::Guard::If(expr)) => (Some(self.to_expr(expr)), Some(expr.span)),
+            Some(hir::Guard::IfLet(lets)) => (Some(self.to_let_expr(lets)), Some(lets.span)),
+            None => (None, None),
         };
+        let arrow_span = self.to_match_arm_arrow_span(guard_span.unwrap_or(arm.pat.span), arm.body.span);
         MatchArm::new(
             self.to_span_id(arm.span),
             self.to_pat(arm.pat),
             guard,
+            self.to_span_id(arrow_span),
             self.to_expr(arm.body),
         )
     }
 
+    /// The `=>` of a match arm isn't stored in the HIR. This function searches
+    /// for it in the source code between the pattern or guard and the body of
+    /// the arm. If it can't be found, the span of the gap will be returned.
+    fn to_match_arm_arrow_span(&self, before: rustc_span::Span, body: rustc_span::Span) -> rustc_span::Span {
+        let gap = before.between(body);
+        self.rustc_cx
+            .sess
+            .source_map()
+            .span_to_snippet(gap)
+            .ok()
+            .and_then(|snippet| snippet.find("=>"))
+            .map_or(gap, |offset| {
+                let lo = gap.lo() + rustc_span::BytePos::from_usize(offset);
+                gap.with_lo(lo).with_hi(lo + rustc_span::BytePos(2))
+            })
+    }
+
     fn to_expr_from_closure(
         &self,
         data: CommonExprData<'ast>,
@@ -439,6 +496,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                             block,
                             None,
                             Syncness::Async,
+                            Constness::NotConst,
                             self.to_capture_kind(closure.capture_clause),
                         )
                     });
@@ -477,9 +535,22 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             None
         };
 
+        // Async closures are desugared to a closure, which returns an async
+        // closure generator containing the actual body.
+        let syncness = if let hir::ExprKind::Closure(inner) = body.value.kind
+            && let Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Closure)) =
+                self.rustc_cx.hir().body(inner.body).generator_kind
+        {
+            Syncness::Async
+        } else {
+            Syncness::Sync
+        };
+
         ClosureExpr::new(
             data,
             self.to_capture_kind(closure.capture_clause),
+            syncness,
+            matches!(closure.movability, Some(hir::Movability::Static)),
             params,
             return_ty,
             self.to_body_id(closure.body),
@@ -494,6 +565,24 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     }
 
     #[must_use]
+    fn to_method_turbofish(
+        &self,
+        expr: &hir::Expr<'tcx>,
+        method: &hir::PathSegment<'tcx>,
+    ) -> Option<&'ast [SemGenericArgKind<'ast>]> {
+        // The generic args of the method segment are only set, if a turbofish was given
+        method.args?;
+
+        // The typeck results contain the args of the parent item, like the impl or
+        // trait, followed by the args of the method itself. Only the latter are
+        // specified by the turbofish.
+        let ty_check = self.rustc_ty_check();
+        let def_id = ty_check.type_dependent_def_id(expr.hir_id)?;
+        let parent_count = self.rustc_cx.generics_of(def_id).parent_count;
+        let args = ty_check.node_substs(expr.hir_id);
+        Some(self.to_sem_generic_arg_kinds(args.get(parent_count..)?))
+    }
+
     fn to_let_expr(&self, lets: &hir::Let<'tcx>) -> ExprKind<'ast> {
         let data = CommonExprData::new(self.to_expr_id(lets.hir_id), self.to_span_id(lets.span));
         ExprKind::Let(self.alloc(LetExpr::new(data, self.to_pat(lets.pat), self.to_expr(lets.init))))
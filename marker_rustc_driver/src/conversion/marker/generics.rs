@@ -33,12 +33,17 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     #[must_use]
     pub fn to_sem_generic_args(&self, args: &[mid::ty::GenericArg<'tcx>]) -> SemGenericArgs<'ast> {
+        SemGenericArgs::new(self.to_sem_generic_arg_kinds(args))
+    }
+
+    #[must_use]
+    pub fn to_sem_generic_arg_kinds(&self, args: &[mid::ty::GenericArg<'tcx>]) -> &'ast [SemGenericArgKind<'ast>] {
         let args: Vec<_> = args
             .iter()
             .filter_map(|arg| self.to_sem_generic_arg_kind(*arg))
             .collect();
 
-        SemGenericArgs::new(self.alloc_slice(args))
+        self.alloc_slice(args)
     }
 
     #[must_use]
@@ -46,9 +51,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         match &arg.unpack() {
             mid::ty::GenericArgKind::Lifetime(_) => None,
             mid::ty::GenericArgKind::Type(ty) => Some(SemGenericArgKind::Ty(self.to_sem_ty(*ty))),
-            mid::ty::GenericArgKind::Const(_) => Some(SemGenericArgKind::Const(
-                self.alloc(SemConstArg::new(ConstValue::new())),
-            )),
+            mid::ty::GenericArgKind::Const(konst) => Some(SemGenericArgKind::Const(self.alloc(SemConstArg::new(
+                self.to_const_value(*konst).unwrap_or(ConstValue::Opaque),
+            )))),
         }
     }
 
@@ -72,27 +77,12 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         // [`E0225`]: https://doc.rust-lang.org/stable/error_codes/E0225.html
         if let Some(main) = bounds.principal() {
             let main = main.skip_binder();
+            let bindings = bounds.projection_bounds().map(|binding| {
+                let binding = binding.skip_binder();
+                (binding.def_id, binding.term)
+            });
 
-            let mut generics: Vec<_> = main
-                .substs
-                .iter()
-                .filter_map(|arg| self.to_sem_generic_arg_kind(arg))
-                .collect();
-
-            bounds
-                .projection_bounds()
-                .for_each(|binding| match binding.skip_binder().term.unpack() {
-                    mid::ty::TermKind::Ty(ty) => generics.push(SemGenericArgKind::Binding(self.alloc(
-                        SemBindingArg::new(self.to_item_id(binding.item_def_id()), self.to_sem_ty(ty)),
-                    ))),
-                    mid::ty::TermKind::Const(_) => todo!(),
-                });
-
-            marker_bounds.push(SemTraitBound::new(
-                false,
-                self.to_ty_def_id(main.def_id),
-                SemGenericArgs::new(self.alloc_slice(generics)),
-            ));
+            marker_bounds.push(self.to_sem_trait_bound(main.def_id, main.substs.iter(), bindings));
         }
 
         bounds
@@ -104,6 +94,67 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
 
         self.alloc_slice(marker_bounds)
     }
+
+    /// This converts the bounds of an opaque type, like `impl Iterator<Item = u8>`.
+    /// Named type parameters are stored as separate projection predicates by
+    /// rustc. These are reattached to the generic args of their trait, like it's
+    /// done in [`Self::to_sem_trait_bounds`].
+    pub fn to_sem_opaque_bounds(
+        &self,
+        def_id: hir::def_id::DefId,
+        substs: &'tcx mid::ty::List<mid::ty::GenericArg<'tcx>>,
+    ) -> &'ast [SemTraitBound<'ast>] {
+        let tcx = self.rustc_cx;
+        let bounds = tcx.item_bounds(def_id).subst(tcx, substs);
+
+        let marker_bounds: Vec<_> = bounds
+            .iter()
+            .filter_map(mid::ty::Clause::as_trait_clause)
+            .map(|trait_clause| {
+                let trait_ref = trait_clause.skip_binder().trait_ref;
+                let bindings = bounds
+                    .iter()
+                    .filter_map(mid::ty::Clause::as_projection_clause)
+                    .map(mid::ty::Binder::skip_binder)
+                    .filter(|projection| projection.projection_ty.trait_def_id(tcx) == trait_ref.def_id)
+                    .map(|projection| (projection.projection_ty.def_id, projection.term));
+
+                // The first arg is the `Self` type, which is the opaque type itself
+                self.to_sem_trait_bound(trait_ref.def_id, trait_ref.substs.iter().skip(1), bindings)
+            })
+            .collect();
+
+        self.alloc_slice(marker_bounds)
+    }
+
+    /// Converts a trait bound from the generic args of the trait and the named
+    /// parameters, which rustc stores separately. The named parameters are
+    /// given as the id of the associated item and the bound term.
+    fn to_sem_trait_bound(
+        &self,
+        trait_id: hir::def_id::DefId,
+        args: impl Iterator<Item = mid::ty::GenericArg<'tcx>>,
+        bindings: impl Iterator<Item = (hir::def_id::DefId, mid::ty::Term<'tcx>)>,
+    ) -> SemTraitBound<'ast> {
+        let mut generics: Vec<_> = args.filter_map(|arg| self.to_sem_generic_arg_kind(arg)).collect();
+
+        for (item_id, term) in bindings {
+            match term.unpack() {
+                mid::ty::TermKind::Ty(ty) => generics.push(SemGenericArgKind::Binding(
+                    self.alloc(SemBindingArg::new(self.to_item_id(item_id), self.to_sem_ty(ty))),
+                )),
+                // Bindings of associated constants, like `Trait<N = 3>`, are unstable
+                // and can't be represented by marker yet. They're skipped for now.
+                mid::ty::TermKind::Const(_) => {},
+            }
+        }
+
+        SemTraitBound::new(
+            false,
+            self.to_ty_def_id(trait_id),
+            SemGenericArgs::new(self.alloc_slice(generics)),
+        )
+    }
 }
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
@@ -160,31 +211,27 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     hir::WherePredicate::BoundPredicate(ty_bound) => {
                         // FIXME Add span to API clause:
                         // let span = to_api_span_id(ty_bound.span);
-                        let params =
-                            SynGenericParams::new(self.to_syn_generic_param_kinds(ty_bound.bound_generic_params), &[]);
+                        let params = SynGenericParams::new(
+                            self.to_syn_generic_param_kinds(ty_bound.bound_generic_params, &[]),
+                            &[],
+                        );
                         let ty = self.to_syn_ty(ty_bound.bounded_ty);
                         Some(SynWhereClauseKind::Ty(self.alloc({
-                            SynTyClause::new(Some(params), ty, self.to_syn_ty_param_bound(predicate.bounds()))
+                            SynTyClause::new(
+                                Some(params),
+                                ty,
+                                self.to_syn_ty_param_bound(predicate.bounds()),
+                                ty_bound.origin == hir::PredicateOrigin::WhereClause,
+                            )
                         })))
                     },
                     hir::WherePredicate::RegionPredicate(lifetime_bound) => {
                         self.to_lifetime(lifetime_bound.lifetime).map(|lifetime| {
-                            SynWhereClauseKind::Lifetime(self.alloc({
-                                let bounds: Vec<_> = lifetime_bound
-                                    .bounds
-                                    .iter()
-                                    .filter_map(|bound| match bound {
-                                        hir::GenericBound::Outlives(lifetime) => self.to_lifetime(lifetime),
-                                        _ => unreachable!("lifetimes can only be bound by lifetimes"),
-                                    })
-                                    .collect();
-                                let bounds = if bounds.is_empty() {
-                                    self.alloc_slice(bounds)
-                                } else {
-                                    &[]
-                                };
-                                SynLifetimeClause::new(lifetime, bounds)
-                            }))
+                            SynWhereClauseKind::Lifetime(self.alloc(SynLifetimeClause::new(
+                                lifetime,
+                                self.to_lifetime_bounds(lifetime_bound.bounds),
+                                lifetime_bound.in_where_clause,
+                            )))
                         })
                     },
                     hir::WherePredicate::EqPredicate(_) => {
@@ -195,10 +242,17 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             .collect();
         let clauses = self.alloc_slice(clauses);
 
-        SynGenericParams::new(self.to_syn_generic_param_kinds(rustc_generics.params), clauses)
+        SynGenericParams::new(
+            self.to_syn_generic_param_kinds(rustc_generics.params, rustc_generics.predicates),
+            clauses,
+        )
     }
 
-    fn to_syn_generic_param_kinds(&self, params: &[hir::GenericParam<'tcx>]) -> &'ast [SynGenericParamKind<'ast>] {
+    fn to_syn_generic_param_kinds(
+        &self,
+        params: &[hir::GenericParam<'tcx>],
+        predicates: &[hir::WherePredicate<'tcx>],
+    ) -> &'ast [SynGenericParamKind<'ast>] {
         if params.is_empty() {
             return &[];
         }
@@ -215,14 +269,52 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 match rustc_param.kind {
                     hir::GenericParamKind::Lifetime {
                         kind: hir::LifetimeParamKind::Explicit,
-                    } => Some(SynGenericParamKind::Lifetime(self.alloc(SynLifetimeParam::new(
-                        id,
-                        name,
-                        Some(span),
-                    )))),
-                    hir::GenericParamKind::Type { synthetic: false, .. } => Some(SynGenericParamKind::Ty(
-                        self.alloc(SynTyParam::new(Some(span), name, id)),
-                    )),
+                    } => {
+                        let outlives = predicates
+                            .iter()
+                            .find_map(|predicate| match predicate {
+                                hir::WherePredicate::RegionPredicate(lifetime_bound)
+                                    if !lifetime_bound.in_where_clause
+                                        && lifetime_bound.is_param_bound(rustc_param.def_id) =>
+                                {
+                                    Some(self.to_lifetime_bounds(lifetime_bound.bounds))
+                                },
+                                _ => None,
+                            })
+                            .unwrap_or_default();
+                        Some(SynGenericParamKind::Lifetime(self.alloc(SynLifetimeParam::new(
+                            id,
+                            name,
+                            Some(span),
+                            outlives,
+                        ))))
+                    },
+                    hir::GenericParamKind::Type {
+                        synthetic: false,
+                        default,
+                    } => {
+                        // Rustc lowers bounds declared with the parameter to
+                        // predicates with a `GenericParam` origin.
+                        let bounds = predicates
+                            .iter()
+                            .find_map(|predicate| match predicate {
+                                hir::WherePredicate::BoundPredicate(ty_bound)
+                                    if ty_bound.origin == hir::PredicateOrigin::GenericParam
+                                        && ty_bound.is_param_bound(rustc_param.def_id.to_def_id()) =>
+                                {
+                                    Some(self.to_syn_ty_param_bound(ty_bound.bounds))
+                                },
+                                _ => None,
+                            })
+                            .unwrap_or_default();
+                        Some(SynGenericParamKind::Ty(self.alloc(SynTyParam::new(
+                            Some(span),
+                            name,
+                            id,
+                            bounds,
+                            default.map(|ty| self.to_syn_ty(ty)),
+                        ))))
+                    },
                     hir::GenericParamKind::Const { ty, default } => {
                         Some(SynGenericParamKind::Const(self.alloc(SynConstParam::new(
                             id,
@@ -252,6 +344,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 hir::GenericBound::Trait(trait_ref, modifier) => {
                     Some(SynTyParamBound::TraitBound(self.alloc(SynTraitBound::new(
                         !matches!(modifier, hir::TraitBoundModifier::None),
+                        self.to_syn_bound_generic_params(trait_ref.bound_generic_params),
                         self.to_trait_ref(&trait_ref.trait_ref),
                         self.to_span_id(bound.span()),
                     ))))
@@ -259,6 +352,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 hir::GenericBound::LangItemTrait(lang_item, span, _, rustc_args) => Some(SynTyParamBound::TraitBound(
                     self.alloc(SynTraitBound::new(
                         false,
+                        None,
                         TraitRef::new(
                             self.to_item_id(
                                 self.rustc_cx
@@ -280,6 +374,26 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         self.alloc_slice(bounds)
     }
 
+    fn to_lifetime_bounds(&self, bounds: &[hir::GenericBound<'tcx>]) -> &'ast [Lifetime<'ast>] {
+        let bounds: Vec<_> = bounds
+            .iter()
+            .filter_map(|bound| match bound {
+                hir::GenericBound::Outlives(lifetime) => self.to_lifetime(lifetime),
+                _ => unreachable!("lifetimes can only be bound by lifetimes"),
+            })
+            .collect();
+        if bounds.is_empty() {
+            &[]
+        } else {
+            self.alloc_slice(bounds)
+        }
+    }
+
+    /// Converts the parameters introduced by a `for<...>` of a trait bound.
+    fn to_syn_bound_generic_params(&self, params: &[hir::GenericParam<'tcx>]) -> Option<SynGenericParams<'ast>> {
+        (!params.is_empty()).then(|| SynGenericParams::new(self.to_syn_generic_param_kinds(params, &[]), &[]))
+    }
+
     pub fn to_syn_ty_param_bound_from_hir(
         &self,
         rust_bounds: &[rustc_hir::PolyTraitRef<'tcx>],
@@ -288,6 +402,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         let traits = rust_bounds.iter().map(|rust_trait_ref| {
             SynTyParamBound::TraitBound(self.storage.alloc(SynTraitBound::new(
                 false,
+                self.to_syn_bound_generic_params(rust_trait_ref.bound_generic_params),
                 self.to_trait_ref(&rust_trait_ref.trait_ref),
                 self.to_span_id(rust_trait_ref.span),
             )))
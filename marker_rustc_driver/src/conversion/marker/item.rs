@@ -87,7 +87,16 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 )))
             },
             hir::ItemKind::Mod(rustc_mod) => {
-                ItemKind::Mod(self.alloc(ModItem::new(data, self.to_items(rustc_mod.item_ids))))
+                let inner_span = rustc_mod.spans.inner_span;
+                let source_map = self.rustc_cx.sess.source_map();
+                let is_inline = source_map.lookup_source_file(rustc_item.span.lo()).start_pos
+                    == source_map.lookup_source_file(inner_span.lo()).start_pos;
+                ItemKind::Mod(self.alloc(ModItem::new(
+                    data,
+                    self.to_items(rustc_mod.item_ids),
+                    is_inline,
+                    self.to_span_id(inner_span),
+                )))
             },
             hir::ItemKind::ForeignMod { abi, items } => ItemKind::ExternBlock(self.alloc({
                 let abi = self.to_abi(*abi);
@@ -110,7 +119,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 let variants = self.alloc_slice(enum_def.variants.iter().map(|variant| {
                     EnumVariant::new(
                         self.to_variant_id(variant.def_id),
-                        self.to_symbol_id(variant.ident.name),
+                        self.to_ident(variant.ident),
                         self.to_span_id(variant.span),
                         self.to_adt_kind(&variant.data),
                         variant.disr_expr.map(|anon| self.to_const_expr(anon)),
@@ -222,6 +231,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                             None,
                         ))),
                         self.to_syn_ty(ty),
+                        &[],
                     )
                 }))
             },
@@ -229,7 +239,12 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 let body = self.rustc_cx.hir().body(body_id);
                 self.with_body(body_id, || {
                     self.alloc_slice(body.params.iter().zip(decl.inputs.iter()).map(|(param, ty)| {
-                        FnParam::new(self.to_span_id(param.span), self.to_pat(param.pat), self.to_syn_ty(ty))
+                        FnParam::new(
+                            self.to_span_id(param.span),
+                            self.to_pat(param.pat),
+                            self.to_syn_ty(ty),
+                            self.to_attrs(self.rustc_cx.hir().attrs(param.hir_id)),
+                        )
                     }))
                 })
             },
@@ -251,9 +266,10 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             Field::new(
                 self.to_field_id(field.hir_id),
                 Visibility::new(self.to_item_id(field.def_id)),
-                self.to_symbol_id(field.ident.name),
+                self.to_ident(field.ident),
                 self.to_syn_ty(field.ty),
                 self.to_span_id(field.span),
+                self.to_attrs(self.rustc_cx.hir().attrs(field.hir_id)),
             )
         }))
     }
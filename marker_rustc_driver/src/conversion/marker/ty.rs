@@ -1,10 +1,10 @@
 use marker_api::ast::{
     ty::{
         CommonSynTyData, NumKind, SemAdtTy, SemAliasTy, SemArrayTy, SemBoolTy, SemClosureTy, SemFnPtrTy, SemFnTy,
-        SemGenericTy, SemNeverTy, SemNumTy, SemRawPtrTy, SemRefTy, SemSliceTy, SemTextTy, SemTraitObjTy, SemTupleTy,
-        SemTyKind, SemUnstableTy, SynArrayTy, SynBoolTy, SynFnPtrTy, SynImplTraitTy, SynInferredTy, SynNeverTy,
-        SynNumTy, SynPathTy, SynRawPtrTy, SynRefTy, SynSliceTy, SynTextTy, SynTraitObjTy, SynTupleTy, SynTyKind,
-        TextKind,
+        SemGenericTy, SemImplTraitTy, SemNeverTy, SemNumTy, SemRawPtrTy, SemRefTy, SemSliceTy, SemTextTy,
+        SemTraitObjTy, SemTupleTy, SemTyKind, SemUnstableTy, SynArrayTy, SynBoolTy, SynFnPtrTy, SynImplTraitTy,
+        SynInferredTy, SynNeverTy, SynNumTy, SynPathTy, SynRawPtrTy, SynRefTy, SynSliceTy, SynTextTy, SynTraitObjTy,
+        SynTupleTy, SynTyKind, TextKind,
     },
     CommonCallableData, ConstValue, Constness, Parameter, Syncness,
 };
@@ -58,9 +58,10 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             mid::ty::TyKind::Foreign(_) => {
                 todo!("foreign type are currently sadly not supported. See rust-marker/marker#182")
             },
-            mid::ty::TyKind::Array(inner, _len) => {
-                SemTyKind::Array(self.alloc(SemArrayTy::new(self.to_sem_ty(*inner), ConstValue::new())))
-            },
+            mid::ty::TyKind::Array(inner, len) => SemTyKind::Array(self.alloc(SemArrayTy::new(
+                self.to_sem_ty(*inner),
+                self.to_const_value(*len).unwrap_or(ConstValue::Opaque),
+            ))),
             mid::ty::TyKind::Slice(inner) => SemTyKind::Slice(self.alloc(SemSliceTy::new(self.to_sem_ty(*inner)))),
             mid::ty::TyKind::Tuple(ty_lst) => SemTyKind::Tuple(self.alloc(SemTupleTy::new(
                 self.alloc_slice(ty_lst.iter().map(|ty| self.to_sem_ty(ty))),
@@ -94,7 +95,10 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 if !matches!(kind, mid::ty::DynKind::Dyn) {
                     unimplemented!("the docs are not totally clear, when `DynStar` is used, her it is: {rustc_ty:#?}")
                 }
-                SemTyKind::TraitObj(self.alloc(SemTraitObjTy::new(self.to_sem_trait_bounds(binders))))
+                SemTyKind::TraitObj(self.alloc(SemTraitObjTy::new(
+                    self.to_sem_trait_bounds(binders),
+                    binders.principal().is_some(),
+                )))
             },
             mid::ty::TyKind::Closure(id, generics) => SemTyKind::ClosureTy(self.alloc(SemClosureTy::new(
                 self.to_ty_def_id(*id),
@@ -104,6 +108,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             | mid::ty::TyKind::GeneratorWitness(_)
             | mid::ty::TyKind::GeneratorWitnessMIR(_, _) => SemTyKind::Unstable(self.alloc(SemUnstableTy::new())),
             mid::ty::TyKind::Never => SemTyKind::Never(self.alloc(SemNeverTy::new())),
+            mid::ty::TyKind::Alias(mid::ty::AliasKind::Opaque, info) => SemTyKind::ImplTrait(
+                self.alloc(SemImplTraitTy::new(self.to_sem_opaque_bounds(info.def_id, info.substs))),
+            ),
             mid::ty::TyKind::Alias(_, info) => {
                 SemTyKind::Alias(self.alloc(SemAliasTy::new(self.to_item_id(info.def_id))))
             },
@@ -299,4 +306,39 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         };
         SynTyKind::Num(self.alloc(SynNumTy::new(data, num_kind)))
     }
+
+    /// Evaluates the given constant. `None` is returned, if the constant can't
+    /// be evaluated, for example, because it depends on generic parameters.
+    #[must_use]
+    pub fn to_const_value(&self, konst: mid::ty::Const<'tcx>) -> Option<ConstValue<'ast>> {
+        let tcx = self.rustc_cx;
+        let konst = konst.eval(tcx, mid::ty::ParamEnv::reveal_all());
+        let mid::ty::ConstKind::Value(valtree) = konst.kind() else {
+            return None;
+        };
+        let value = match (konst.ty().kind(), valtree) {
+            (mid::ty::TyKind::Bool, mid::ty::ValTree::Leaf(scalar)) => ConstValue::Bool(scalar.try_to_bool().ok()?),
+            (mid::ty::TyKind::Char, mid::ty::ValTree::Leaf(scalar)) => ConstValue::Char(char::try_from(scalar).ok()?),
+            (mid::ty::TyKind::Int(_), mid::ty::ValTree::Leaf(scalar)) => {
+                #[allow(clippy::cast_sign_loss, reason = "negative values are sign extended on purpose")]
+                let value = scalar.try_to_int(scalar.size()).ok()? as u128;
+                ConstValue::Integer(value)
+            },
+            (mid::ty::TyKind::Uint(_), mid::ty::ValTree::Leaf(scalar)) => {
+                ConstValue::Integer(scalar.try_to_uint(scalar.size()).ok()?)
+            },
+            (mid::ty::TyKind::Float(mid::ty::FloatTy::F32), mid::ty::ValTree::Leaf(scalar)) => {
+                ConstValue::Float(f32::from_bits(scalar.try_to_u32().ok()?).into())
+            },
+            (mid::ty::TyKind::Float(mid::ty::FloatTy::F64), mid::ty::ValTree::Leaf(scalar)) => {
+                ConstValue::Float(f64::from_bits(scalar.try_to_u64().ok()?))
+            },
+            (mid::ty::TyKind::Ref(_, inner, _), mid::ty::ValTree::Branch(_)) if inner.is_str() => {
+                let bytes = valtree.try_to_raw_bytes(tcx, konst.ty())?;
+                ConstValue::Str(self.storage.alloc_str(std::str::from_utf8(bytes).ok()?))
+            },
+            _ => ConstValue::Opaque,
+        };
+        Some(value)
+    }
 }
@@ -1,4 +1,5 @@
 mod common;
+mod ty;
 mod unstable;
 
 use std::cell::RefCell;
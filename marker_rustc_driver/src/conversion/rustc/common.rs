@@ -2,8 +2,8 @@ use std::mem::{size_of, transmute};
 
 use marker_api::{
     ast::{
-        BodyId, CrateId, ExprId, FieldId, GenericId, ItemId, LetStmtId, Span, SpanId, StmtIdInner, SymbolId, TyDefId,
-        VarId, VariantId,
+        BodyId, CrateId, ExprId, FieldId, GenericId, ItemId, LetStmtId, Span, SpanId, SpanSrcId, StmtIdInner, SymbolId,
+        TyDefId, VarId, VariantId,
     },
     diagnostic::{Applicability, EmissionNode},
     lint::Level,
@@ -108,6 +108,11 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
         unsafe { transmute(api_id) }
     }
 
+    #[must_use]
+    pub fn to_syntax_context(&self, api_id: SpanSrcId) -> rustc_span::SyntaxContext {
+        transmute_id!(SpanSrcId as rustc_span::SyntaxContext = api_id)
+    }
+
     #[must_use]
     pub fn to_span_from_id(&self, api_id: SpanId) -> rustc_span::Span {
         assert_eq!(
@@ -141,6 +146,7 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
             },
             EmissionNode::Field(id) => return Some(self.to_hir_id(id)),
             EmissionNode::Variant(id) => self.to_def_id(id),
+            EmissionNode::GenericParam(id) => self.to_def_id(id),
             _ => unreachable!(),
         };
 
@@ -177,6 +183,7 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
         match api_level {
             Level::Allow => rustc_lint::Level::Allow,
             Level::Warn => rustc_lint::Level::Warn,
+            Level::ForceWarn => rustc_lint::Level::ForceWarn(None),
             Level::Deny => rustc_lint::Level::Deny,
             Level::Forbid => rustc_lint::Level::Forbid,
             _ => unreachable!(),
@@ -0,0 +1,123 @@
+use marker_api::ast::{
+    generic::SemGenericArgKind,
+    ty::{NumKind, SemTyKind, TextKind},
+    Mutability,
+};
+use rustc_middle as mid;
+
+use super::RustcConverter;
+
+impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
+    /// This converts a semantic type back to the rustc representation. Semantic
+    /// types are a lossy representation, they don't contain lifetimes or the
+    /// values of constants. Lifetimes are therefore replaced by erased regions.
+    /// `None` is returned, if the type can't be reconstructed.
+    #[must_use]
+    pub fn to_ty(&self, api_ty: SemTyKind<'ast>) -> Option<mid::ty::Ty<'tcx>> {
+        let tcx = self.rustc_cx;
+        let ty = match api_ty {
+            SemTyKind::Bool(_) => tcx.types.bool,
+            SemTyKind::Num(num) => match num.numeric_kind() {
+                NumKind::Isize => tcx.types.isize,
+                NumKind::I8 => tcx.types.i8,
+                NumKind::I16 => tcx.types.i16,
+                NumKind::I32 => tcx.types.i32,
+                NumKind::I64 => tcx.types.i64,
+                NumKind::I128 => tcx.types.i128,
+                NumKind::Usize => tcx.types.usize,
+                NumKind::U8 => tcx.types.u8,
+                NumKind::U16 => tcx.types.u16,
+                NumKind::U32 => tcx.types.u32,
+                NumKind::U64 => tcx.types.u64,
+                NumKind::U128 => tcx.types.u128,
+                NumKind::F32 => tcx.types.f32,
+                NumKind::F64 => tcx.types.f64,
+                _ => unreachable!(),
+            },
+            SemTyKind::Text(text) => match text.textual_kind() {
+                TextKind::Char => tcx.types.char,
+                TextKind::Str => tcx.types.str_,
+                _ => unreachable!(),
+            },
+            SemTyKind::Never(_) => tcx.types.never,
+            SemTyKind::Tuple(tuple) => {
+                let tys = tuple
+                    .types()
+                    .iter()
+                    .map(|ty| self.to_ty(*ty))
+                    .collect::<Option<Vec<_>>>()?;
+                mid::ty::Ty::new_tup(tcx, &tys)
+            },
+            SemTyKind::Slice(slice) => mid::ty::Ty::new_slice(tcx, self.to_ty(slice.inner_ty())?),
+            SemTyKind::Array(array) => {
+                mid::ty::Ty::new_array(tcx, self.to_ty(array.inner_ty())?, array.len().as_u64()?)
+            },
+            SemTyKind::Ref(reference) => mid::ty::Ty::new_ref(
+                tcx,
+                tcx.lifetimes.re_erased,
+                mid::ty::TypeAndMut {
+                    ty: self.to_ty(reference.inner_ty())?,
+                    mutbl: self.to_mutability(reference.mutability()),
+                },
+            ),
+            SemTyKind::RawPtr(ptr) => mid::ty::Ty::new_ptr(
+                tcx,
+                mid::ty::TypeAndMut {
+                    ty: self.to_ty(ptr.inner_ty())?,
+                    mutbl: self.to_mutability(ptr.mutability()),
+                },
+            ),
+            SemTyKind::Adt(adt) => {
+                let def_id = self.to_def_id(adt.def_id());
+                let mut api_args = adt.generics().args().iter();
+                let mut failed = false;
+                let args = mid::ty::InternalSubsts::for_item(tcx, def_id, |param, _| match param.kind {
+                    mid::ty::GenericParamDefKind::Lifetime => tcx.lifetimes.re_erased.into(),
+                    mid::ty::GenericParamDefKind::Type { .. } => {
+                        let ty = api_args.next().and_then(|arg| match arg {
+                            SemGenericArgKind::Ty(ty) => self.to_ty(*ty),
+                            SemGenericArgKind::Binding(_) | SemGenericArgKind::Const(_) => None,
+                            _ => unreachable!(),
+                        });
+                        if let Some(ty) = ty {
+                            ty.into()
+                        } else {
+                            failed = true;
+                            tcx.types.unit.into()
+                        }
+                    },
+                    // The values of constants are currently not stored in
+                    // semantic types, they can therefore not be reconstructed.
+                    mid::ty::GenericParamDefKind::Const { .. } => {
+                        failed = true;
+                        tcx.consts.unit.into()
+                    },
+                });
+                if failed || api_args.next().is_some() {
+                    return None;
+                }
+                mid::ty::Ty::new_adt(tcx, tcx.adt_def(def_id), args)
+            },
+            // These types depend on the context, they were created in.
+            SemTyKind::FnTy(_)
+            | SemTyKind::ClosureTy(_)
+            | SemTyKind::FnPtr(_)
+            | SemTyKind::TraitObj(_)
+            | SemTyKind::ImplTrait(_)
+            | SemTyKind::Generic(_)
+            | SemTyKind::Alias(_)
+            | SemTyKind::Unstable(_) => return None,
+            _ => unreachable!(),
+        };
+        Some(ty)
+    }
+
+    #[must_use]
+    pub fn to_mutability(&self, api_mutability: Mutability) -> mid::mir::Mutability {
+        match api_mutability {
+            Mutability::Mut => mid::mir::Mutability::Mut,
+            Mutability::Unmut => mid::mir::Mutability::Not,
+            _ => unreachable!(),
+        }
+    }
+}
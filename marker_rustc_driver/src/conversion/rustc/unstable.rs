@@ -21,7 +21,8 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
             let lint: &'static rustc_lint::Lint = lints.borrow_mut().entry(api_lint).or_insert_with(move || {
                 // Not extracted to an extra function, as it's very specific
                 let report_in_external_macro = match api_lint.report_in_macro {
-                    MacroReport::No => false,
+                    // Marker checks if the macro is local for `YesWithMacroNote`
+                    MacroReport::No | MacroReport::YesWithMacroNote => false,
                     MacroReport::All => true,
                     _ => unreachable!(),
                 };
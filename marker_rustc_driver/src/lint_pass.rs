@@ -1,7 +1,7 @@
 use std::cell::OnceCell;
 
 use marker_adapter::{Adapter, AdapterError, LintCrateInfo};
-use marker_api::lint::Lint;
+use marker_api::lint::{Lint, LintGroup};
 
 use crate::context::{storage::Storage, RustcContext};
 
@@ -43,6 +43,30 @@ impl RustcLintPass {
                 .collect()
         })
     }
+
+    /// Returns the lint groups of all loaded lints, together with the lints
+    /// they contain. Groups with the same name are merged.
+    pub fn marker_lint_groups() -> Vec<(&'static LintGroup, Vec<&'static Lint>)> {
+        group_lints(Self::marker_lints())
+    }
+}
+
+/// Collects the groups of the given lints, in the order they first appear,
+/// together with the lints they contain. Groups with the same name are merged.
+/// Lints without a group are skipped.
+pub fn group_lints(lints: impl IntoIterator<Item = &'static Lint>) -> Vec<(&'static LintGroup, Vec<&'static Lint>)> {
+    let mut groups: Vec<(&'static LintGroup, Vec<&'static Lint>)> = vec![];
+    for lint in lints {
+        let Some(group) = lint.group.copy() else {
+            continue;
+        };
+        if let Some((_, lints)) = groups.iter_mut().find(|(known, _)| known.name == group.name) {
+            lints.push(lint);
+        } else {
+            groups.push((group, vec![lint]));
+        }
+    }
+    groups
 }
 
 rustc_lint_defs::impl_lint_pass!(RustcLintPass => []);
@@ -72,7 +96,7 @@ fn process_crate_lifetime<'ast, 'tcx: 'ast>(
     // To support debug printing of AST nodes, as these might sometimes require the
     // context. Note that this only sets the cx for the rustc side. Each lint crate
     // has their own storage for cx.
-    marker_api::context::set_ast_cx(driver_cx.ast_cx());
+    let _cx_guard = marker_api::context::set_ast_cx(driver_cx.ast_cx());
 
     let krate = driver_cx
         .marker_converter
@@ -22,6 +22,7 @@ extern crate rustc_errors;
 extern crate rustc_hash;
 extern crate rustc_hir;
 extern crate rustc_hir_analysis;
+extern crate rustc_infer;
 extern crate rustc_interface;
 extern crate rustc_lint;
 extern crate rustc_lint_defs;
@@ -29,6 +30,7 @@ extern crate rustc_middle;
 extern crate rustc_session;
 extern crate rustc_span;
 extern crate rustc_target;
+extern crate rustc_trait_selection;
 
 pub mod context;
 pub mod conversion;
@@ -39,7 +41,9 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::process::{exit, Command};
 
-use marker_adapter::{LintCrateInfo, LINT_CRATES_ENV};
+use marker_adapter::{
+    Adapter, LintCrateInfo, LINT_CRATES_ENV, MAX_DIAGNOSTICS_ENV, OUTPUT_FORMAT_ENV, RUN_ID_ENV, TIMINGS_ENV,
+};
 use rustc_session::config::ErrorOutputType;
 use rustc_session::EarlyErrorHandler;
 
@@ -78,11 +82,15 @@ impl rustc_driver::Callbacks for MarkerCallback {
         // code is executed.
         assert!(config.register_lints.is_none());
         let lint_crates = std::mem::take(&mut self.lint_crates);
-        config.register_lints = Some(Box::new(move |_sess, lint_store| {
+        config.register_lints = Some(Box::new(move |sess, lint_store| {
             // It looks like it can happen, that the `config` function is called
             // with a different thread than the actual lint pass later, how interesting.
             // This will not make sure that the adapter is always initiated.
-            lint_pass::RustcLintPass::init_adapter(&lint_crates).unwrap();
+            if let Err(err) = lint_pass::RustcLintPass::init_adapter(&lint_crates) {
+                // A fatal error is reported like other compilation errors, instead
+                // of an ICE, since the user has to fix the lint crate.
+                sess.fatal(err.to_string());
+            }
             // Register lints from lint crates. This is required to have rustc track
             // the lint level correctly.
             let lints: Vec<_> = lint_pass::RustcLintPass::marker_lints()
@@ -90,6 +98,14 @@ impl rustc_driver::Callbacks for MarkerCallback {
                 .map(RustcConverter::static_to_lint)
                 .collect();
             lint_store.register_lints(&lints);
+            for (group, lints) in lint_pass::RustcLintPass::marker_lint_groups() {
+                let lint_ids = lints
+                    .into_iter()
+                    .map(|lint| rustc_lint::LintId::of(RustcConverter::static_to_lint(lint)))
+                    .collect();
+                let name: &'static str = Box::leak(group.name.to_ascii_lowercase().into_boxed_str());
+                lint_store.register_group(true, name, None, lint_ids);
+            }
 
             lint_store.register_late_pass(|_| Box::new(lint_pass::RustcLintPass));
         }));
@@ -203,6 +219,9 @@ Common options:
         --rustc              Pass all arguments to rustc
     -V, --version            Print version information and exit
         --toolchain          Print the required toolchain and API version
+        --explain-lint <LINT>
+                             Print the explanation of a lint from the lint crates
+        --list-lints         Print all lints from the lint crates, organized by group
 
 ---
 
@@ -211,6 +230,86 @@ interfacing with the driver directly and use `cargo marker` instead.
 "
     );
 }
+
+/// Loads the lint crates specified by the [`LINT_CRATES_ENV`] environment value.
+fn load_adapter_from_env() -> Adapter {
+    let lint_crates = match LintCrateInfo::list_from_env() {
+        Ok(lint_crates) => lint_crates,
+        Err(err) => panic!("Error while determining the lint crates to load: {err:#?}"),
+    };
+    Adapter::new(&lint_crates).unwrap()
+}
+
+/// Prints all lints from the lint crates and exits. Lints are organized by
+/// their group, followed by lints without a group.
+fn list_lints() -> ! {
+    let infos = load_adapter_from_env().lint_pass_infos();
+    let lints: Vec<_> = infos
+        .iter()
+        .flat_map(marker_api::LintPassInfo::lints)
+        .copied()
+        .collect();
+
+    let print_lint = |lint: &marker_api::lint::Lint| {
+        let level = format!("{:?}", lint.default_level).to_ascii_lowercase();
+        let summary = lint_summary(lint.explanation);
+        println!("    {:<40} {level:<10} {summary}", lint.name.to_ascii_lowercase());
+    };
+    for (group, group_lints) in lint_pass::group_lints(lints.iter().copied()) {
+        println!("{}:", group.name.to_ascii_lowercase());
+        group_lints.into_iter().for_each(print_lint);
+        println!();
+    }
+    if lints.iter().any(|lint| lint.group.is_none()) {
+        println!("lints without a group:");
+        lints
+            .iter()
+            .filter(|lint| lint.group.is_none())
+            .for_each(|lint| print_lint(lint));
+    }
+
+    exit(0);
+}
+
+/// Returns the first paragraph of the lint explanation, which isn't a heading.
+/// This is usually the description from the "What it does" section.
+fn lint_summary(explanation: &str) -> String {
+    explanation
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty() || line.starts_with('#'))
+        .take_while(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Prints the explanation of the given lint and exits. The lint is searched in
+/// the lint crates specified by the [`LINT_CRATES_ENV`] environment value. The
+/// `marker::` prefix of the lint name is optional.
+fn explain_lint(lint_name: &str) -> ! {
+    let adapter = load_adapter_from_env();
+
+    let lint_name = lint_name.strip_prefix("marker::").unwrap_or(lint_name);
+    let full_name = format!("marker::{}", lint_name.replace('-', "_"));
+    let infos = adapter.lint_pass_infos();
+    let lint = infos
+        .iter()
+        .flat_map(marker_api::LintPassInfo::lints)
+        .find(|lint| lint.name.eq_ignore_ascii_case(&full_name));
+
+    if let Some(lint) = lint {
+        // The explanation is created from doc comments, which usually start
+        // with a space after the `///`
+        for line in lint.explanation.trim_end().lines() {
+            println!("{}", line.strip_prefix(' ').unwrap_or(line));
+        }
+        exit(0);
+    }
+
+    eprintln!("error: no lint named `{full_name}` was found in the loaded lint crates");
+    exit(1);
+}
+
 const BUG_REPORT_URL: &str = "https://github.com/rust-marker/marker/issues/new?template=panic.yml";
 
 fn main() {
@@ -269,6 +368,14 @@ fn main() {
             exit(0);
         }
 
+        if let Some(lint_name) = arg_value(&orig_args, "--explain-lint", |_| true) {
+            explain_lint(lint_name);
+        }
+
+        if orig_args.iter().any(|a| a == "--list-lints") {
+            list_lints();
+        }
+
         // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
         // We're invoking the compiler programmatically, so we'll ignore this.
         let wrapper_mode = orig_args.get(1).map(Path::new).and_then(Path::file_stem) == Some("rustc".as_ref());
@@ -295,8 +402,23 @@ fn main() {
         let in_primary_package = env::var("CARGO_PRIMARY_PACKAGE").is_ok();
 
         let enable_marker = !cap_lints_allow && (!no_deps || in_primary_package);
-        let env_vars = vec![(LINT_CRATES_ENV, std::env::var(LINT_CRATES_ENV).unwrap_or_default())];
+        let mut env_vars = vec![
+            (LINT_CRATES_ENV, std::env::var(LINT_CRATES_ENV).unwrap_or_default()),
+            (TIMINGS_ENV, std::env::var(TIMINGS_ENV).unwrap_or_default()),
+            (OUTPUT_FORMAT_ENV, std::env::var(OUTPUT_FORMAT_ENV).unwrap_or_default()),
+            (
+                MAX_DIAGNOSTICS_ENV,
+                std::env::var(MAX_DIAGNOSTICS_ENV).unwrap_or_default(),
+            ),
+        ];
         if enable_marker {
+            // Cargo only replays the cached stderr output of fresh crates. Diagnostics
+            // printed to stdout would be lost. Tracking the run id makes sure, that
+            // these crates are checked again.
+            if let Ok(run_id) = std::env::var(RUN_ID_ENV) {
+                env_vars.push((RUN_ID_ENV, run_id));
+            }
+
             let lint_crates = match LintCrateInfo::list_from_env() {
                 Ok(lint_crates) => lint_crates,
                 Err(marker_adapter::AdapterError::LintCratesEnvUnset) => vec![],
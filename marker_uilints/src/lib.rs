@@ -3,17 +3,24 @@
 
 use marker_api::{
     ast::{
-        item::{EnumVariant, Field, StaticItem},
+        expr::{ArrayExprKind, IfBranch},
+        generic::{SynConstParam, SynGenericParamKind, SynLifetimeParam, SynTyParamBound, SynWhereClauseKind},
+        item::{Body, EnumVariant, Field, FnItem, FnKind, FnParam, ModItem, StaticItem, StructItem, TraitItem},
         ty::SemTyKind,
-        Span,
+        visitor::{for_each_expr, walk_expr, Visitor},
+        ItemId, Span, TyPath,
     },
     diagnostic::{Applicability, EmissionNode},
     prelude::*,
     LintPass, LintPassInfo, LintPassInfoBuilder,
 };
+use std::ops::ControlFlow;
 
 #[derive(Default)]
-struct TestLintPass {}
+struct TestLintPass {
+    /// The function, which patterns should be checked by `check_pat`
+    check_pat_fn: Option<ItemId>,
+}
 
 marker_api::export_lint_pass!(TestLintPass);
 
@@ -36,6 +43,25 @@ marker_api::declare_lint! {
     marker_api::lint::MacroReport::All,
 }
 
+marker_api::declare_lint! {
+    /// # What it does
+    /// A lint used for marker's uitests.
+    ///
+    /// It's used to test the macro note for lints in local macros
+    MACRO_NOTE_LINT,
+    Warn,
+    marker_api::lint::MacroReport::YesWithMacroNote,
+}
+
+marker_api::declare_lint! {
+    /// # What it does
+    /// A lint used for marker's uitests.
+    ///
+    /// It's used to report the return value of [`AstContext::emit_lint`]
+    EMIT_RESULT_LINT,
+    Warn,
+}
+
 marker_api::declare_lint! {
     /// # What it does
     /// A lint used for markers uitests.
@@ -43,6 +69,12 @@ marker_api::declare_lint! {
     /// It warns about about item names starting with `FindMe`, `find_me` or `FIND_ME`.
     ITEM_WITH_TEST_NAME,
     Warn,
+    group: UITESTS,
+}
+
+marker_api::declare_lint_group! {
+    /// Lints used for markers uitests.
+    UITESTS,
 }
 
 fn emit_item_with_test_name_lint<'ast>(
@@ -57,16 +89,40 @@ fn emit_item_with_test_name_lint<'ast>(
 
 impl LintPass for TestLintPass {
     fn info(&self) -> LintPassInfo {
-        LintPassInfoBuilder::new(Box::new([TEST_LINT, ITEM_WITH_TEST_NAME])).build()
+        LintPassInfoBuilder::new(Box::new([
+            TEST_LINT,
+            ITEM_WITH_TEST_NAME,
+            MACRO_NOTE_LINT,
+            EMIT_RESULT_LINT,
+        ]))
+        .build()
     }
 
+    #[allow(clippy::too_many_lines)]
     fn check_item<'ast>(&mut self, cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
         if let ItemKind::Fn(item) = item {
+            self.check_pat_fn = item
+                .ident()
+                .filter(|ident| ident.name().starts_with("check_pat"))
+                .map(|_| item.id());
+
             if let Some(ident) = item.ident() {
                 if ident.name() == "test_ty_id_resolution_trigger" {
                     test_ty_id_resolution(cx);
                 } else if ident.name() == "uilints_please_ice_on_this" {
                     panic!("free ice cream for everyone!!!");
+                } else if ident.name().starts_with("find_returns") {
+                    check_returns(cx, item);
+                } else if ident.name().starts_with("multi_span") {
+                    cx.emit_lint_for_each_span(
+                        TEST_LINT,
+                        item.id(),
+                        "found parameters in a multi span test",
+                        item.params().iter().map(FnParam::span),
+                        |diag| {
+                            diag.note(format!("parameters: {}", item.params().len()));
+                        },
+                    );
                 }
             }
         }
@@ -75,6 +131,63 @@ impl LintPass for TestLintPass {
             check_static_item(cx, item);
         }
 
+        if let ItemKind::Mod(item) = item {
+            if item
+                .ident()
+                .map_or(false, |ident| ident.name().starts_with("check_mod"))
+            {
+                check_mod_item(cx, item);
+            }
+        }
+
+        if let ItemKind::Struct(item) = item {
+            if item
+                .ident()
+                .map_or(false, |ident| ident.name().starts_with("FieldNames"))
+            {
+                check_struct_field_names(cx, item);
+            }
+        }
+
+        if let ItemKind::Const(item) = item {
+            if let Some(ident) = item.ident().filter(|ident| ident.name().starts_with("CONST_VALUE")) {
+                cx.emit_lint(TEST_LINT, item.id(), "checking const item", ident.span(), |diag| {
+                    let value = item.value();
+                    diag.note(format!("value: {:?}", value.map(|expr| expr.span().snippet_or(".."))));
+                    if let Some(ExprKind::IntLit(lit)) = value {
+                        diag.note(format!("int literal value: {}", lit.value()));
+                    }
+                });
+            }
+        }
+
+        if let ItemKind::Enum(item) = item {
+            if item
+                .ident()
+                .map_or(false, |ident| ident.name().starts_with("EnumVariants"))
+            {
+                cx.emit_lint(
+                    TEST_LINT,
+                    item.id(),
+                    "checking enum variants",
+                    item.ident().unwrap().span(),
+                    |diag| {
+                        diag.note(format!("variant count: {}", item.variant_count()));
+                        diag.note(format!(
+                            "variant names: {:?}",
+                            item.variant_names().map(Ident::name).collect::<Vec<_>>()
+                        ));
+                    },
+                );
+            }
+        }
+
+        if let ItemKind::Trait(item) = item {
+            if item.ident().map(marker_api::ast::Ident::name) == Some("TraitImplsCheck") {
+                check_trait_impls(cx, item);
+            }
+        }
+
         if matches!(
             item.ident().map(marker_api::ast::Ident::name),
             Some(name) if name.starts_with("FindMe") || name.starts_with("FIND_ME") || name.starts_with("find_me")
@@ -97,6 +210,25 @@ impl LintPass for TestLintPass {
             }
         }
 
+        if let Some(ident) = item.ident() {
+            if ident.name().starts_with("EMIT_RESULT") {
+                let emitted = cx.emit_lint(
+                    TEST_LINT,
+                    item.id(),
+                    "checking the emission result",
+                    item.span(),
+                    |_| {},
+                );
+                cx.emit_lint(
+                    EMIT_RESULT_LINT,
+                    item.id(),
+                    format!("`emit_lint` returned: {emitted}"),
+                    ident.span(),
+                    |_| {},
+                );
+            }
+        }
+
         if matches!(
             item.ident().map(marker_api::ast::Ident::name),
             Some(name) if name.starts_with("PrintMe") || name.starts_with("PRINT_ME") || name.starts_with("print_me")
@@ -112,6 +244,47 @@ impl LintPass for TestLintPass {
             );
         }
 
+        if matches!(
+            item.ident().map(marker_api::ast::Ident::name),
+            Some(name) if name.starts_with("check_deprecation")
+        ) {
+            cx.emit_lint(
+                TEST_LINT,
+                item.id(),
+                "checking deprecation",
+                item.ident().unwrap().span(),
+                |diag| {
+                    diag.note(format!("is deprecated: {}", cx.is_deprecated(item.id())));
+                    diag.note(format!("since: {:?}", cx.deprecated_since(item.id())));
+                    diag.note(format!("note: {:?}", cx.deprecated_note(item.id())));
+                },
+            );
+        }
+
+        if let Some(name) = item.ident().map(marker_api::ast::Ident::name) {
+            if name.starts_with("check_attrs") {
+                check_item_attrs(cx, item);
+            } else if name.starts_with("check_item_path") || name.starts_with("CheckItemPath") {
+                check_item_path(cx, item);
+            } else if name.starts_with("check_vis") {
+                cx.emit_lint(TEST_LINT, item.id(), "checking visibility", item.span(), |diag| {
+                    diag.note(format!("visibility: {:?}", cx.item_visibility(item.id())));
+                });
+            } else if name.starts_with("check_generics") || name.starts_with("CheckGenerics") {
+                check_generic_params(cx, item);
+            } else if name == "check_all_items" {
+                check_all_items(cx, item);
+            } else if name.starts_with("check_parent") {
+                check_parent_item(cx, item);
+            } else if name.starts_with("check_def_span") || name.starts_with("CheckDefSpan") {
+                cx.emit_lint(TEST_LINT, item.id(), "checking definition span", item.span(), |diag| {
+                    if let Some(span) = cx.definition_span(item.id()) {
+                        diag.span_note("defined here", span);
+                    }
+                });
+            }
+        }
+
         if let ItemKind::Fn(func) = item {
             if matches!(
                 item.ident().map(marker_api::ast::Ident::name),
@@ -135,6 +308,20 @@ impl LintPass for TestLintPass {
         if field.ident().starts_with("find_me") {
             emit_item_with_test_name_lint(cx, field.id(), "a field", field.span());
         }
+        if field.ident().starts_with("check_attrs") {
+            cx.emit_lint(
+                TEST_LINT,
+                field.id(),
+                "checking field attributes",
+                field.span(),
+                |diag| {
+                    for attr in field.attrs() {
+                        let path: Vec<_> = attr.path().iter().map(marker_api::ast::Ident::name).collect();
+                        diag.span_note(format!("field attribute with path: {:?}", path.join("::")), attr.span());
+                    }
+                },
+            );
+        }
     }
 
     fn check_variant<'ast>(&mut self, cx: &'ast AstContext<'ast>, variant: &'ast EnumVariant<'ast>) {
@@ -143,6 +330,58 @@ impl LintPass for TestLintPass {
         }
     }
 
+    fn check_generic_param<'ast>(&mut self, cx: &'ast AstContext<'ast>, param: &'ast SynGenericParamKind<'ast>) {
+        let (name, desc) = match param {
+            SynGenericParamKind::Lifetime(lt) => (
+                lt.name(),
+                format!("lifetime param outliving {} lifetime(s)", lt.outlives().len()),
+            ),
+            SynGenericParamKind::Ty(ty) => (ty.name(), "type param".to_string()),
+            SynGenericParamKind::Const(con) => (
+                con.name(),
+                format!(
+                    "const param of type `{}` with default {:?}",
+                    con.ty().span().snippet_or(".."),
+                    con.default().map(|default| default.expr().span().snippet_or("..")),
+                ),
+            ),
+            _ => unreachable!(),
+        };
+        if name
+            .trim_start_matches('\'')
+            .to_lowercase()
+            .replace('_', "")
+            .starts_with("findme")
+        {
+            emit_item_with_test_name_lint(cx, param.id(), desc, param.span().unwrap());
+        }
+    }
+
+    fn check_fn<'ast>(&mut self, cx: &'ast AstContext<'ast>, fn_kind: FnKind<'ast>, body: &'ast Body<'ast>) {
+        let (is_check_fn, param_count) = match fn_kind {
+            FnKind::Fn(item) => (
+                item.ident().map_or(false, |ident| ident.name().starts_with("check_fn")),
+                item.params().len(),
+            ),
+            FnKind::Closure(expr) => (
+                expr.params()
+                    .iter()
+                    .any(|param| matches!(param.pat(), PatKind::Ident(ident) if ident.name().starts_with("check_fn"))),
+                expr.params().len(),
+            ),
+            _ => unreachable!(),
+        };
+
+        if is_check_fn {
+            cx.emit_lint(TEST_LINT, body.expr().id(), "checking fn", fn_kind.span(), |diag| {
+                diag.note(format!("params: {param_count}"));
+                diag.note(format!("has return type: {}", fn_kind.return_ty().is_some()));
+                diag.span_note("with this body", body.expr().span());
+            });
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
     fn check_stmt<'ast>(&mut self, cx: &'ast AstContext<'ast>, stmt: StmtKind<'ast>) {
         // I didn't realize that `let_chains` are still unstable. This makes the
         // code significantly less readable -.-
@@ -152,12 +391,157 @@ impl LintPass for TestLintPass {
             if ident.name().starts_with("_print") {
                 cx.emit_lint(TEST_LINT, stmt.id(), "print test", stmt.span(), |diag| {
                     diag.note(format!("{expr:#?}"));
+                    if let ExprKind::FloatLit(lit) = expr {
+                        diag.note(format!("raw value: `{}`", lit.raw_value()));
+                    }
+                    if let ExprKind::Ctor(ctor) = expr {
+                        diag.note(format!("is struct update: {}", ctor.is_struct_update()));
+                    }
                 });
+            } else if ident.name().starts_with("_turbofish") {
+                if let ExprKind::Method(method) = expr {
+                    cx.emit_lint(TEST_LINT, stmt.id(), "print turbofish", method.span(), |diag| {
+                        diag.note(format!("turbofish: {:#?}", method.turbofish()));
+                    });
+                }
             } else if ident.name().starts_with("_span") {
                 cx.emit_lint(PRINT_SPAN_LINT, stmt.id(), "print span", stmt.span(), |diag| {
                     let span = expr.span();
                     diag.note(format!("Debug: {span:#?}"));
                     diag.note(format!("Snippet: {}", span.snippet_or("..")));
+                    diag.note(format!(
+                        "Line and column: {:?} to {:?}",
+                        span.start_line_col(),
+                        span.end_line_col()
+                    ));
+                    diag.note(format!("File name: {:?}", span.file_name()));
+                    diag.note(format!(
+                        "Expanded to lines: {:?}",
+                        span.expand_to_line().map(|lines| lines.snippet_or(".."))
+                    ));
+                    if let Some(info) = span.src_id().and_then(|id| cx.macro_info(id)) {
+                        diag.span_note(format!("Expanded from macro `{}`", info.name()), info.call_site_span());
+                    }
+                    if let ExprKind::Match(match_expr) = expr {
+                        for arm in match_expr.arms() {
+                            diag.span_note("match arm arrow", arm.arrow_span());
+                        }
+                        if let [first, .., last] = match_expr.arms() {
+                            if let Some(arms_span) = first.span().merge(last.span()) {
+                                diag.span_note("merged span of all arms", &arms_span);
+                                diag.note(format!(
+                                    "match contains arms: {}, first arm overlaps arms: {}, first arm overlaps last arm: {}",
+                                    span.contains(&arms_span),
+                                    first.span().overlaps(&arms_span),
+                                    first.span().overlaps(last.span()),
+                                ));
+                            }
+                        }
+                    }
+                });
+            } else if ident.name().starts_with("_lookup") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "expr lookup test", stmt.span(), |diag| {
+                    let found = cx.expr(expr.id()).map(|found| found.id());
+                    diag.note(format!("Found the same expression: {}", found == Some(expr.id())));
+                });
+            } else if ident.name().starts_with("_impl_check") {
+                let name = if ident.name().starts_with("_impl_check_alias") {
+                    "ImplCheckAlias"
+                } else {
+                    "ImplCheck"
+                };
+                let trait_ids = cx.resolve_ty_ids(TyPath::new("crate").segment(name));
+                let (Some(trait_id), SemTyKind::Tuple(tuple)) = (trait_ids.first(), expr.ty()) else {
+                    return;
+                };
+                let [ty, args @ ..] = tuple.types() else { return };
+                cx.emit_lint(
+                    TEST_LINT,
+                    stmt.id(),
+                    "checking trait implementation",
+                    stmt.span(),
+                    |diag| {
+                        let implements = cx.type_implements_trait(*ty, *trait_id, args);
+                        diag.note(format!("implements `{name}`: {implements}"));
+                    },
+                );
+            } else if ident.name().starts_with("_auto_traits") {
+                let ty = expr.ty();
+                cx.emit_lint(TEST_LINT, stmt.id(), "checking auto traits", stmt.span(), |diag| {
+                    diag.note(format!("is copy: {}", cx.is_type_copy(ty)));
+                    diag.note(format!("is send: {}", cx.is_type_send(ty)));
+                    diag.note(format!("is sync: {}", cx.is_type_sync(ty)));
+                });
+            } else if ident.name().starts_with("_if_branches") {
+                let ExprKind::If(if_expr) = expr else { return };
+                cx.emit_lint(TEST_LINT, stmt.id(), "checking if branches", stmt.span(), |diag| {
+                    for branch in if_expr.branches() {
+                        match branch {
+                            IfBranch::Condition { then, .. } => diag.span_note("condition branch", then.span()),
+                            IfBranch::Else(els) => diag.span_note("else branch", els.span()),
+                            _ => unreachable!(),
+                        };
+                    }
+                });
+            } else if ident.name().starts_with("_array_kind") {
+                let ExprKind::Array(array) = expr else { return };
+                cx.emit_lint(
+                    TEST_LINT,
+                    stmt.id(),
+                    "checking array kind",
+                    stmt.span(),
+                    |diag| match array.kind() {
+                        ArrayExprKind::List { elements } => {
+                            diag.note(format!("list with {} elements", elements.len()));
+                        },
+                        ArrayExprKind::Repeat { value, count } => {
+                            diag.span_note("repeated value", value.span());
+                            diag.span_note("repeat count", count.expr().span());
+                        },
+                        _ => unreachable!(),
+                    },
+                );
+            } else if ident.name().starts_with("_visit_lits") {
+                let lits = visited_int_lits(cx, expr);
+                cx.emit_lint(TEST_LINT, stmt.id(), "checking visited literals", stmt.span(), |diag| {
+                    diag.note(format!("visited int literals: {lits:?}"));
+                });
+            } else if ident.name().starts_with("_const_value") {
+                let Some(SynTyKind::Array(array)) = lets.ty() else {
+                    return;
+                };
+                let Some(len) = array.len() else { return };
+                cx.emit_lint(TEST_LINT, stmt.id(), "checking const value", stmt.span(), |diag| {
+                    diag.note(format!("value: {:?}", cx.resolve_const_value(len)));
+                });
+            } else if ident.name().starts_with("_callee") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "checking callee", stmt.span(), |diag| {
+                    let callee = cx.callee_of(expr.id());
+                    diag.note(format!("resolved: {}", callee.is_some()));
+                    diag.note(format!("path: {:?}", callee.and_then(|id| cx.resolve_item_path(id))));
+                });
+            } else if ident.name().starts_with("_field_ty") {
+                let SemTyKind::Adt(adt) = expr.ty() else { return };
+                cx.emit_lint(TEST_LINT, stmt.id(), "checking field types", stmt.span(), |diag| {
+                    for name in ["0", "value", "missing"] {
+                        diag.note(format!("field `{name}`: {:?}", adt.field_ty(name)));
+                    }
+                });
+            } else if ident.name().starts_with("_tuple_len") {
+                if let SemTyKind::Tuple(tuple) = expr.ty() {
+                    cx.emit_lint(TEST_LINT, stmt.id(), "checking tuple length", stmt.span(), |diag| {
+                        diag.note(format!("len: {}, is empty: {}", tuple.len(), tuple.is_empty()));
+                    });
+                }
+            } else if ident.name().starts_with("_diverging") {
+                cx.emit_lint(TEST_LINT, stmt.id(), "checking divergence", stmt.span(), |diag| {
+                    diag.note(format!("is diverging: {}", expr.is_diverging()));
+                });
+            } else if ident.name().starts_with("_ty_eq") {
+                let ExprKind::Tuple(tuple) = expr else { return };
+                let [a, b] = tuple.elements() else { return };
+                cx.emit_lint(TEST_LINT, stmt.id(), "checking type equality", stmt.span(), |diag| {
+                    diag.note(format!("types are equal: {}", a.ty() == b.ty()));
                 });
             } else if ident.name().starts_with("_ty") {
                 cx.emit_lint(TEST_LINT, stmt.id(), "print type test", stmt.span(), |diag| {
@@ -187,11 +571,74 @@ impl LintPass for TestLintPass {
             }
         }
     }
+
+    fn check_pat<'ast>(&mut self, cx: &'ast AstContext<'ast>, pat: PatKind<'ast>) {
+        let Some(fn_id) = self.check_pat_fn else {
+            return;
+        };
+
+        let msg = match pat {
+            PatKind::Ident(ident) => format!("ident pattern `{}`", ident.name()),
+            PatKind::Wildcard(_) => "wildcard pattern".to_string(),
+            PatKind::Rest(_) => "rest pattern".to_string(),
+            PatKind::Ref(_) => "ref pattern".to_string(),
+            PatKind::Struct(_) => "struct pattern".to_string(),
+            PatKind::Tuple(_) => "tuple pattern".to_string(),
+            PatKind::Slice(_) => "slice pattern".to_string(),
+            PatKind::Or(_) => "or pattern".to_string(),
+            PatKind::Lit(..) => "literal pattern".to_string(),
+            PatKind::Range(_) => "range pattern".to_string(),
+            _ => "other pattern".to_string(),
+        };
+        cx.emit_lint(TEST_LINT, fn_id, format!("checking {msg}"), pat.span(), |_| {});
+    }
+}
+
+/// Collects the values of all integer literals in the expression, in the
+/// order they're visited by [`walk_expr`].
+fn visited_int_lits<'ast>(cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> Vec<u128> {
+    struct IntLitVisitor(Vec<u128>);
+
+    impl<'ast> Visitor<'ast, ()> for IntLitVisitor {
+        fn visit_expr(&mut self, _cx: &'ast AstContext<'ast>, expr: ExprKind<'ast>) -> ControlFlow<()> {
+            if let ExprKind::IntLit(lit) = expr {
+                self.0.push(lit.value());
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut visitor = IntLitVisitor(vec![]);
+    let _ = walk_expr(cx, &mut visitor, expr);
+    visitor.0
+}
+
+/// Emits a lint for every `return` expression in the function, in the order
+/// they're visited by [`for_each_expr`].
+fn check_returns<'ast>(cx: &'ast AstContext<'ast>, item: &'ast FnItem<'ast>) {
+    let Some(body_id) = item.body_id() else {
+        return;
+    };
+
+    let mut index = 0;
+    let _: Option<()> = for_each_expr(cx, cx.body(body_id), |expr| {
+        if let ExprKind::Return(ret) = expr {
+            index += 1;
+            cx.emit_lint(
+                TEST_LINT,
+                ret.id(),
+                format!("visited return expression #{index}"),
+                ret.span(),
+                |_| {},
+            );
+        }
+        ControlFlow::Continue(())
+    });
 }
 
 fn check_static_item<'ast>(cx: &'ast AstContext<'ast>, item: &'ast StaticItem<'ast>) {
-    if let Some(name) = item.ident() {
-        let name = name.name();
+    if let Some(ident) = item.ident() {
+        let name = ident.name();
         if name.starts_with("PRINT_TYPE") {
             cx.emit_lint(TEST_LINT, item.id(), "printing type for", item.ty().span(), |_| {});
             eprintln!("{:#?}\n\n", item.ty());
@@ -209,6 +656,56 @@ fn check_static_item<'ast>(cx: &'ast AstContext<'ast>, item: &'ast StaticItem<'a
                     diag.span_suggestion("try", item.span(), "duck", Applicability::Unspecified);
                 },
             );
+        } else if name.starts_with("RENAME_ITEM") {
+            cx.emit_lint(
+                TEST_LINT,
+                item.id(),
+                "this item should be renamed",
+                ident.span(),
+                |diag| {
+                    diag.suggest_rename(ident, "RENAMED_ITEM");
+                    if let Some(sugg) = cx.format_with_span("{span}_V2", ident.span()) {
+                        diag.span_suggestion("or add a version", ident.span(), sugg, Applicability::MaybeIncorrect);
+                    }
+                },
+            );
+        } else if name.starts_with("CHECK_STATIC") {
+            cx.emit_lint(TEST_LINT, item.id(), "checking static item", ident.span(), |diag| {
+                diag.note(format!("mutability: {:?}", item.mutability()));
+                diag.note(format!("is_extern: {}", item.is_extern()));
+                if let Some(value) = item.value() {
+                    diag.span_note("initialized by this value", value.span());
+                }
+            });
+        } else if name.starts_with("MACRO_NOTE") {
+            cx.emit_lint(MACRO_NOTE_LINT, item.id(), "a static item", item.span(), |_| {});
+        } else if name.starts_with("MULTIPART_ITEM") {
+            cx.emit_lint(
+                TEST_LINT,
+                item.id(),
+                "this item should be changed",
+                ident.span(),
+                |diag| {
+                    diag.multipart_suggestion(
+                        "rename it and use a larger type",
+                        [(ident.span(), "CHANGED_ITEM"), (item.ty().span(), "u64")],
+                        Applicability::MachineApplicable,
+                    );
+                    diag.span_label("this type is too small", item.ty().span());
+                    diag.span_suggestion(
+                        "or make it public",
+                        &item.span().shrink_to_lo(),
+                        "pub ",
+                        Applicability::MaybeIncorrect,
+                    );
+                    diag.span_suggestion(
+                        "or add a comment",
+                        &item.span().shrink_to_hi(),
+                        " // Changed",
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
         }
     }
 }
@@ -253,8 +750,212 @@ fn test_ty_id_resolution<'ast>(cx: &'ast AstContext<'ast>) {
         "Check equal: {}",
         cx.resolve_ty_ids("item_id_resolution::TestType") == cx.resolve_ty_ids("crate::TestType")
     );
+    eprintln!(
+        "Check built path: {}",
+        cx.resolve_ty_ids(TyPath::new("crate").segment("TestType")) == cx.resolve_ty_ids("crate::TestType")
+    );
 
     eprintln!();
     eprintln!("=====================================================================");
     eprintln!();
 }
+
+fn check_struct_field_names<'ast>(cx: &'ast AstContext<'ast>, item: &'ast StructItem<'ast>) {
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "checking struct field names",
+        item.ident().unwrap().span(),
+        |diag| {
+            let Some(names) = item.field_names() else {
+                diag.note("field names: None");
+                return;
+            };
+            let names: Vec<_> = names.collect();
+            diag.note(format!("field names: {}", names.len()));
+            for ident in names {
+                diag.span_note(format!("field name: `{}`", ident.name()), ident.span());
+            }
+        },
+    );
+}
+
+fn check_item_attrs<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "checking item attributes",
+        item.ident().unwrap().span(),
+        |diag| {
+            for attr in cx.item_attrs(item.id()) {
+                let path: Vec<_> = attr.path().iter().map(marker_api::ast::Ident::name).collect();
+                diag.span_note(
+                    format!(
+                        "path: {:?}, value: {:?}, doc comment: {}",
+                        path.join("::"),
+                        attr.value_str(),
+                        attr.is_doc_comment()
+                    ),
+                    attr.span(),
+                );
+            }
+            diag.note(format!("has `must_use`: {}", cx.has_attr(item.id(), "must_use")));
+            if let ItemKind::Fn(func) = item {
+                for param in func.params() {
+                    for attr in param.attrs() {
+                        let path: Vec<_> = attr.path().iter().map(marker_api::ast::Ident::name).collect();
+                        diag.span_note(
+                            format!("parameter attribute with path: {:?}", path.join("::")),
+                            attr.span(),
+                        );
+                    }
+                }
+            }
+        },
+    );
+}
+
+fn check_item_path<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "checking item path",
+        item.ident().unwrap().span(),
+        |diag| {
+            diag.note(format!("path: {:?}", cx.resolve_item_path(item.id())));
+        },
+    );
+}
+
+fn check_generic_params<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    let generics = match item {
+        ItemKind::Fn(item) => item.generics(),
+        ItemKind::TyAlias(item) => item.generics(),
+        ItemKind::Struct(item) => item.generics(),
+        ItemKind::Enum(item) => item.generics(),
+        ItemKind::Union(item) => item.generics(),
+        ItemKind::Trait(item) => item.generics(),
+        ItemKind::Impl(item) => item.generics(),
+        _ => return,
+    };
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "checking generic parameters",
+        item.ident().unwrap().span(),
+        |diag| {
+            for param in generics.ty_params() {
+                let bounds: Vec<_> = param
+                    .bounds()
+                    .iter()
+                    .map(|bound| match bound {
+                        SynTyParamBound::Lifetime(lt) => lt.label().unwrap_or("'_").to_string(),
+                        SynTyParamBound::TraitBound(bound) => format!(
+                            "{} (relaxed: {}, for-params: {})",
+                            bound.span().snippet_or(".."),
+                            bound.is_relaxed(),
+                            bound.params().map_or(0, |params| params.params().len()),
+                        ),
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                diag.note(format!(
+                    "type param `{}` with bounds {bounds:?} and default {:?}",
+                    param.name(),
+                    param.default().map(|ty| ty.span().snippet_or("..")),
+                ));
+            }
+            let lifetimes: Vec<_> = generics.lifetime_params().map(SynLifetimeParam::name).collect();
+            let consts: Vec<_> = generics.const_params().map(SynConstParam::name).collect();
+            diag.note(format!("lifetime params: {lifetimes:?}, const params: {consts:?}"));
+            for clause in generics.clauses() {
+                let (bounded, bounds) = match clause {
+                    SynWhereClauseKind::Lifetime(clause) => (
+                        clause.lifetime().label().unwrap_or("'_").to_string(),
+                        clause.bounds().len(),
+                    ),
+                    SynWhereClauseKind::Ty(clause) => (clause.ty().span().snippet_or(".."), clause.bounds().len()),
+                    _ => unreachable!(),
+                };
+                diag.note(format!(
+                    "clause for `{bounded}` with {bounds} bound(s), in where clause: {}",
+                    clause.in_where_clause()
+                ));
+            }
+            diag.note(format!("where clauses: {}", generics.where_clauses().count()));
+        },
+    );
+}
+
+fn check_all_items<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "checking all items",
+        item.ident().unwrap().span(),
+        |diag| {
+            for id in cx.all_items() {
+                let name = cx
+                    .item(id)
+                    .and_then(|item| item.ident().map(|ident| ident.name().to_string()));
+                diag.note(format!("item: {name:?}"));
+            }
+        },
+    );
+}
+
+fn check_mod_item<'ast>(cx: &'ast AstContext<'ast>, item: &'ast ModItem<'ast>) {
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "checking module",
+        item.ident().unwrap().span(),
+        |diag| {
+            diag.note(format!("is_inline: {}", item.is_inline()));
+            diag.note(format!("file_path: {:?}", item.file_path()));
+            diag.note(format!("items: {}", item.items().len()));
+        },
+    );
+}
+
+fn check_parent_item<'ast>(cx: &'ast AstContext<'ast>, item: ItemKind<'ast>) {
+    let parent = cx.parent_item(item.id()).and_then(|id| cx.item(id)).map(|parent| {
+        let kind = match parent {
+            ItemKind::Mod(_) => "mod",
+            ItemKind::Fn(_) => "fn",
+            ItemKind::Trait(_) => "trait",
+            ItemKind::Impl(_) => "impl",
+            _ => "item",
+        };
+        match parent.ident().filter(|ident| !ident.name().is_empty()) {
+            Some(ident) => format!("{kind} `{}`", ident.name()),
+            None => kind.to_string(),
+        }
+    });
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "checking parent item",
+        item.ident().unwrap().span(),
+        |diag| {
+            diag.note(format!("parent: {parent:?}"));
+        },
+    );
+}
+
+fn check_trait_impls<'ast>(cx: &'ast AstContext<'ast>, item: &'ast TraitItem<'ast>) {
+    let trait_id = cx.resolve_ty_ids("crate::TraitImplsCheck")[0];
+    cx.emit_lint(
+        TEST_LINT,
+        item.id(),
+        "checking trait impls",
+        item.ident().unwrap().span(),
+        |diag| {
+            for impl_id in cx.trait_impls(trait_id) {
+                if let Some(impl_item) = cx.item(*impl_id) {
+                    diag.span_note("implemented here", impl_item.span());
+                }
+            }
+        },
+    );
+}
@@ -0,0 +1,27 @@
+mod utils {
+    pub fn helper(value: u32) -> u32 {
+        value
+    }
+
+    pub struct Counter(pub u32);
+
+    impl Counter {
+        pub fn get(&self) -> u32 {
+            self.0
+        }
+    }
+}
+
+fn main() {
+    let counter = utils::Counter(1);
+    let _callee_fn = utils::helper(1);
+    let _callee_method = counter.get();
+    let _callee_assoc = utils::Counter::get(&counter);
+    let _callee_std = std::mem::size_of::<u32>();
+    let _callee_trait_method = counter.0.clone();
+    let _callee_ctor = Some(1);
+    let _callee_closure = (|| 1)();
+    let fn_ptr: fn(u32) -> u32 = utils::helper;
+    let _callee_fn_ptr = fn_ptr(1);
+    let _callee_not_a_call = 1;
+}
@@ -0,0 +1,19 @@
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn check_pat_nested((first, (second, _)): (u32, (u32, u32)), point: &Point) {
+    let &Point { x, y: ref renamed_y } = point;
+    let [head, .., tail] = [1, 2, 3, 4];
+    match Some((first, second)) {
+        Some((1 | 2, inner @ 3..=5)) => {},
+        _ => {},
+    }
+}
+
+fn no_check_pat(ignored: u32) {
+    let (a, b) = (ignored, ignored);
+}
+
+fn main() {}
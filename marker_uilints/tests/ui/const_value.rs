@@ -0,0 +1,21 @@
+const LEN: usize = 4;
+
+const CONST_VALUE_LIT: usize = 8;
+const CONST_VALUE_EXPR: usize = LEN * 2;
+
+trait ConstValueTrait {
+    const CONST_VALUE_ASSOC_DEFAULT: u32 = 1;
+    const CONST_VALUE_ASSOC_NO_DEFAULT: u32;
+}
+
+fn generic<const N: usize>() {
+    let _const_value_generic: [u8; N] = [0; N];
+}
+
+fn main() {
+    let _const_value_lit: [u8; 2] = [0; 2];
+    let _const_value_const: [u8; LEN] = [0; LEN];
+    let _const_value_expr: [u8; LEN * 2 + 1] = [0; 9];
+    let _const_value_empty: [u8; 0] = [];
+    generic::<3>();
+}
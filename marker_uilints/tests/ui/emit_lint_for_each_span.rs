@@ -0,0 +1,5 @@
+fn multi_span_params(a: u32, b: u32, c: &str) {}
+
+fn multi_span_empty() {}
+
+fn main() {}
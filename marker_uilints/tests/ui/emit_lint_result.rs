@@ -0,0 +1,17 @@
+#![feature(register_tool)]
+#![register_tool(marker)]
+
+const EMIT_RESULT_DEFAULT: i32 = 0;
+
+#[allow(marker::test_lint)]
+const EMIT_RESULT_ALLOW: i32 = 0;
+
+macro_rules! local_const {
+    ($name:ident) => {
+        const $name: i32 = 0;
+    };
+}
+
+local_const!(EMIT_RESULT_IN_MACRO);
+
+fn main() {}
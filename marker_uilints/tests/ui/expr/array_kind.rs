@@ -0,0 +1,5 @@
+fn main() {
+    let _array_kind_list = [1, 2, 3, 4];
+    let _array_kind_empty: [u32; 0] = [];
+    let _array_kind_repeat = [6; 3];
+}
@@ -0,0 +1,7 @@
+fn main() {
+    let _lookup_int = 17;
+    let _lookup_block = {
+        let x = 5;
+        x + 3
+    };
+}
@@ -0,0 +1,19 @@
+fn find_returns(x: u32) -> u32 {
+    if x == 0 {
+        return 1;
+    }
+
+    let closure = || {
+        return 2;
+    };
+
+    return if x == 1 {
+        return closure();
+    } else {
+        3
+    };
+}
+
+fn main() {
+    find_returns(1);
+}
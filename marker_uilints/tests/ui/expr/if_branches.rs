@@ -0,0 +1,14 @@
+fn main() {
+    let num = 5;
+    let _if_branches_single = if num == 1 { 1 } else { 2 };
+    let _if_branches_chain = if num == 1 {
+        1
+    } else if num == 2 {
+        2
+    } else if num == 3 {
+        3
+    } else {
+        4
+    };
+    let _if_branches_no_else = if num == 1 {};
+}
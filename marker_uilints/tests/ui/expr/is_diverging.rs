@@ -0,0 +1,18 @@
+fn diverging_loop() {
+    let _diverging_loop = loop {};
+}
+
+fn diverging_return() {
+    let _diverging_return = return;
+}
+
+fn diverging_panic() {
+    let _diverging_panic = panic!("oh no");
+}
+
+fn main() {
+    let _diverging_block = { 1 };
+    let _diverging_break = loop {
+        break 2;
+    };
+}
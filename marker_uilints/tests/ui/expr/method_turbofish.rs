@@ -0,0 +1,14 @@
+struct Data;
+
+impl Data {
+    fn with_generics<T: Default, const N: usize>(&self) -> [T; N] {
+        std::array::from_fn(|_| T::default())
+    }
+}
+
+fn main() {
+    let data = [1u8, 2, 3];
+    let _turbofish_none: Vec<u8> = data.iter().copied().collect();
+    let _turbofish_infer = data.iter().copied().collect::<Vec<_>>();
+    let _turbofish_const = Data.with_generics::<u16, 2>();
+}
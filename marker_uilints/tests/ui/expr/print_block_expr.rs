@@ -0,0 +1,5 @@
+#![feature(inline_const)]
+
+fn main() {
+    let _print_const_block = const { 1 + 2 };
+}
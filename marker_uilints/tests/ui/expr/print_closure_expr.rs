@@ -1,3 +1,5 @@
+#![feature(async_closure)]
+
 fn main() {
     let a = "Hey".to_string();
 
@@ -10,4 +12,5 @@ fn main() {
     let _print_pattern_in_arg: fn((u32, u32, u32)) -> () = |(a, b, c)| { /*...*/ };
     // Make sure the infer type stays, if it originates from the source code
     let _print_infer_ty: fn(u32) -> () = |x: _| { /*...*/ };
+    let _print_async = async move |x: u32| x;
 }
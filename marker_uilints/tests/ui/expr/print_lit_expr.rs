@@ -0,0 +1,19 @@
+fn main() {
+    let _print_int = 42;
+    let _print_int = 42u8;
+    let _print_int = 42_usize;
+    let _print_int = 42i128;
+
+    let _print_float = 1.0;
+    let _print_float = 1.0e10_f64;
+    let _print_float = 2.5f32;
+    let _print_float = 1_000.5e3f32;
+    let _print_float = 2_f32;
+    let _print_float = 1_000.000_1;
+    let _print_float = 1e-3_f64;
+
+    let _print_str = "Hello World!";
+    let _print_str = r"Hello World!";
+    let _print_str = r##"Hello "#" World!"##;
+    let _print_str = br#"Hello World!"#;
+}
@@ -0,0 +1,10 @@
+fn main() {
+    let _print_for = 'outer: for _ in 0..2 {
+        break 'outer;
+    };
+
+    let cond = false;
+    let _print_while = 'inner: while cond {
+        continue 'inner;
+    };
+}
@@ -0,0 +1,8 @@
+fn main() {
+    let _visit_lits_half_open = 1..2;
+    let _visit_lits_closed = 3..=4;
+    let _visit_lits_from = 5..;
+    let _visit_lits_to = ..6;
+    let _visit_lits_to_inclusive = ..=7;
+    let _visit_lits_full = ..;
+}
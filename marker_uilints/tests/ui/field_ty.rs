@@ -0,0 +1,17 @@
+struct Named<T> {
+    value: T,
+}
+
+struct Tuple<T>(T, u8);
+
+enum Either {
+    Left { value: u8 },
+    Right { value: u16 },
+}
+
+fn main() {
+    let _field_ty_named = Named { value: 1u16 };
+    let _field_ty_tuple = Tuple(true, 1);
+    let _field_ty_enum = Either::Left { value: 1 };
+    let _ = Either::Right { value: 2 };
+}
@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+struct Wrapper<'find_me_long, 'find_me_short: 'find_me_long, FindMeTy, const FIND_ME_CONST: usize = 3> {
+    _data: &'find_me_short &'find_me_long [FindMeTy; FIND_ME_CONST],
+}
+
+fn generic_fn<FindMeFnTy: Copy, Other>(_: FindMeFnTy, _: Other) {}
+
+fn main() {}
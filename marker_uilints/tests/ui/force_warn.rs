@@ -0,0 +1,8 @@
+//@compile-flags: --force-warn marker::item_with_test_name
+#![feature(register_tool)]
+#![register_tool(marker)]
+
+#[allow(marker::item_with_test_name)]
+const FIND_ME_FORCE_WARN: i32 = 0;
+
+fn main() {}
@@ -0,0 +1,3 @@
+pub fn in_file_module() {}
+
+pub struct InFileModule;
@@ -0,0 +1,21 @@
+mod outer {
+    pub struct First;
+
+    mod inner {
+        fn nested() {
+            struct InBody;
+        }
+    }
+
+    pub trait Second {
+        fn assoc();
+    }
+}
+
+impl outer::Second for outer::First {
+    fn assoc() {}
+}
+
+fn check_all_items() {}
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![allow(dead_code, non_camel_case_types)]
+
+pub fn check_attrs_none() {}
+
+/// Some documentation
+#[must_use]
+#[inline]
+pub fn check_attrs_fn() -> u32 {
+    1
+}
+
+pub fn check_attrs_params(#[allow(unused_variables)] value: u32, #[allow(unused_mut)] _other: u32) {}
+
+#[doc = "Written as an attribute"]
+#[rustfmt::skip]
+#[derive(Debug, Clone)]
+pub struct check_attrs_struct;
+
+pub union CheckAttrsUnion {
+    /// A documented field
+    #[allow(unused)]
+    check_attrs_field: u32,
+    check_attrs_none: f32,
+}
+
+mod check_attrs_mod {
+    #![allow(unused)]
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+pub fn check_def_span_fn(value: u32) -> u32 {
+    value + 1
+}
+
+pub struct CheckDefSpanStruct {
+    field: u32,
+}
+
+pub trait CheckDefSpanTrait {
+    fn method(&self);
+}
+
+fn main() {}
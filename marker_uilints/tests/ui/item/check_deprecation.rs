@@ -0,0 +1,11 @@
+#![allow(deprecated)]
+
+pub fn check_deprecation_none() {}
+
+#[deprecated]
+pub fn check_deprecation_plain() {}
+
+#[deprecated(since = "1.2.0", note = "use `check_deprecation_none` instead")]
+pub fn check_deprecation_full() {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+enum EnumVariantsMixed {
+    Unit,
+    Tuple(u32),
+    Field { value: String },
+}
+
+enum EnumVariantsEmpty {}
+
+fn main() {}
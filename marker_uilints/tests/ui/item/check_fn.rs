@@ -0,0 +1,20 @@
+fn check_fn_free(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+struct CheckFn;
+
+impl CheckFn {
+    fn check_fn_method(&self) {}
+}
+
+trait CheckFnTrait {
+    // Functions without a body are not passed to `check_fn`
+    fn check_fn_decl(&self);
+}
+
+fn main() {
+    let closure = |check_fn_param: u32| -> u32 { check_fn_param * 2 };
+    let _ = check_fn_free(1, closure(2));
+    CheckFn.check_fn_method();
+}
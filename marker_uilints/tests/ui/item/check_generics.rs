@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+struct CheckGenericsStruct<'a, T: Clone + Debug = String, U: ?Sized + 'a = str, const N: usize = 3>
+where
+    T: Default,
+{
+    _data: PhantomData<&'a (T, [u8; N])>,
+    _unsized: PhantomData<&'a U>,
+}
+
+fn check_generics_fn<'a, 'b: 'a, T, F: for<'x> Fn(&'x T) -> &'x T>(_value: &'b T, _f: F)
+where
+    'a: 'static,
+    T: Copy + 'a,
+{
+}
+
+trait CheckGenericsTrait<T: Copy> {}
+
+fn main() {}
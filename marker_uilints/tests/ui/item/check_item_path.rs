@@ -0,0 +1,21 @@
+pub struct CheckItemPathRoot;
+
+pub mod outer {
+    pub mod inner {
+        pub fn check_item_path_nested() {}
+    }
+
+    pub trait CheckItemPathTrait {
+        fn check_item_path_trait_fn();
+    }
+
+    impl CheckItemPathTrait for () {
+        fn check_item_path_trait_fn() {}
+    }
+}
+
+fn body() {
+    struct CheckItemPathInBody;
+}
+
+fn main() {}
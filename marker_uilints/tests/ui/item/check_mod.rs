@@ -0,0 +1,10 @@
+mod check_mod_inline {
+    fn inline_fn() {}
+}
+
+mod check_mod_empty {}
+
+#[path = "auxiliary/check_mod_file.rs"]
+mod check_mod_file;
+
+fn main() {}
@@ -0,0 +1,23 @@
+pub fn check_parent_root() {}
+
+mod outer {
+    pub mod inner {
+        pub fn check_parent_nested() {}
+    }
+
+    pub struct Struct;
+
+    impl super::Trait for Struct {
+        fn check_parent_assoc() {}
+    }
+}
+
+pub trait Trait {
+    fn check_parent_assoc();
+}
+
+fn body() {
+    fn check_parent_in_body() {}
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+static CHECK_STATIC_IMMUTABLE: u32 = 1 + 2;
+
+static mut CHECK_STATIC_MUTABLE: &str = "mutable";
+
+extern "C" {
+    static CHECK_STATIC_EXTERN: u32;
+}
+
+fn main() {}
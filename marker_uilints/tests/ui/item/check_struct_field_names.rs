@@ -0,0 +1,12 @@
+struct FieldNamesNamed {
+    first: u32,
+    second_field: String,
+}
+
+struct FieldNamesEmpty {}
+
+struct FieldNamesUnit;
+
+struct FieldNamesTuple(u32, String);
+
+fn main() {}
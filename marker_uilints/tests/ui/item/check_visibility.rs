@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+pub fn check_vis_pub() {}
+pub(crate) fn check_vis_pub_crate() {}
+fn check_vis_private() {}
+pub(in crate) fn check_vis_pub_in_crate() {}
+
+mod outer {
+    pub(super) fn check_vis_outer_pub_super() {}
+    pub(in crate::outer) fn check_vis_outer_pub_in_self() {}
+
+    pub mod inner {
+        pub(super) fn check_vis_pub_super() {}
+        pub(in crate::outer) fn check_vis_pub_in_outer() {}
+        pub(self) fn check_vis_pub_self() {}
+        pub(crate) fn check_vis_inner_pub_crate() {}
+
+        pub mod innermost {
+            pub(in crate::outer) fn check_vis_pub_in() {}
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![feature(register_tool)]
+#![register_tool(marker)]
+
+#[allow(marker::uitests)]
+const FIND_ME_GROUP_ALLOW: i32 = 0;
+
+#[deny(marker::uitests)]
+const FIND_ME_GROUP_DENY: i32 = 0;
+
+#[deny(marker::uitests)]
+#[allow(marker::item_with_test_name)]
+const FIND_ME_LINT_ALLOW: i32 = 0;
+
+fn main() {}
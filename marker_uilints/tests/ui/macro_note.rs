@@ -0,0 +1,11 @@
+macro_rules! local_static {
+    ($name:ident) => {
+        static $name: u32 = 1;
+    };
+}
+
+static MACRO_NOTE_OUTSIDE: u32 = 1;
+
+local_static!(MACRO_NOTE_INSIDE);
+
+fn main() {}
@@ -0,0 +1,3 @@
+static MULTIPART_ITEM: u32 = 4;
+
+fn main() {}
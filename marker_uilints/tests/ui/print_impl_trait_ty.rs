@@ -0,0 +1,25 @@
+trait Container {
+    type Assoc;
+    type Other;
+}
+
+#[derive(Clone)]
+struct Data;
+
+impl Container for Data {
+    type Assoc = u16;
+    type Other = String;
+}
+
+fn make_assoc() -> impl Container<Assoc = u16> {
+    Data
+}
+
+fn make_assoc_multiple() -> impl Container<Assoc = u16, Other = String> + Clone {
+    Data
+}
+
+fn main() {
+    let _ty_assoc = make_assoc();
+    let _ty_assoc_multiple = make_assoc_multiple();
+}
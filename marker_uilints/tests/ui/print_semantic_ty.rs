@@ -49,6 +49,10 @@ fn u32_to_f32(_: u32) -> f32 {
     0.0
 }
 
+fn make_iter() -> impl Iterator<Item = u8> + Clone {
+    std::iter::once(1)
+}
+
 fn main() {
     let mut x = 0;
     let _ty: u32 = 10;
@@ -66,6 +70,9 @@ fn main() {
     // to be stored in a value, to become a function pointer
     let fn_ptr: fn(u32) -> f32 = u32_to_f32;
     let _ty_fn_ptr: fn(u32) -> f32 = fn_ptr;
+    let _ty_fn_ptr_abi: Option<unsafe extern "system" fn(u32)> = None;
+    let _ty_fn_ptr_abi_unwind: Option<extern "C-unwind" fn()> = None;
+    let _ty_fn_ptr_abi_sysv64: Option<extern "sysv64" fn()> = None;
 
     // Interestingly, rustc substitutes the type directly and the semantic type
     // doesn't show the type alias.
@@ -74,4 +81,6 @@ fn main() {
     let _ty_adt: String = String::new();
     let _ty_dyn_simple: Option<Box<dyn Debug>> = None;
     let _ty_dyn_complex: Option<Box<dyn Iterator<Item = i32> + 'static>> = None;
+    let _ty_dyn_auto: Option<Box<dyn Send + Sync>> = None;
+    let _ty_impl_trait = make_iter();
 }
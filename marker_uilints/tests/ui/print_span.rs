@@ -9,4 +9,10 @@ fn main() {
     let _span_normal = 178;
 
     let _span_macro = magic_macro!();
+
+    let _span_match = match _span_normal {
+        0 => 1,
+        x if x > 10 => 2,
+        _ => 3,
+    };
 }
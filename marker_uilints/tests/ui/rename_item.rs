@@ -0,0 +1,3 @@
+static RENAME_ITEM: u32 = 4;
+
+fn main() {}
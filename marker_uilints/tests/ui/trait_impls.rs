@@ -0,0 +1,19 @@
+trait TraitImplsCheck {}
+
+struct Local;
+
+impl TraitImplsCheck for Local {}
+
+impl TraitImplsCheck for u32 {}
+
+impl<T> TraitImplsCheck for Vec<T> {}
+
+mod nested {
+    impl super::TraitImplsCheck for String {}
+}
+
+trait Other {}
+
+impl Other for Local {}
+
+fn main() {}
@@ -0,0 +1,5 @@
+fn main() {
+    let _tuple_len_unit = ();
+    let _tuple_len_single = (1u8,);
+    let _tuple_len_triple = (1u8, "two", 3.0f32);
+}
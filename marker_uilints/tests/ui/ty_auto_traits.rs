@@ -0,0 +1,11 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+fn main() {
+    let _auto_traits_u32 = 0u32;
+    let _auto_traits_string = String::new();
+    let _auto_traits_rc = Rc::new(0u32);
+    let _auto_traits_cell = Cell::new(0u32);
+    let _auto_traits_arc_mutex = Arc::new(Mutex::new(0u32));
+}
@@ -0,0 +1,21 @@
+struct Wrapper<T>(T);
+
+fn generic<T: Default, U: Default>() {
+    let _ty_eq_generic_same = (T::default(), T::default());
+    let _ty_eq_generic_different = (T::default(), U::default());
+}
+
+fn main() {
+    let _ty_eq_prim = (1u8, 2u8);
+    let _ty_eq_prim_different = (1u8, 2u16);
+    let _ty_eq_ref = (&1u8, &2u8);
+    let _ty_eq_ref_mut_different = (&1u8, &mut 2u8);
+    let _ty_eq_adt = (Wrapper(1u8), Wrapper(2u8));
+    let _ty_eq_adt_different = (Wrapper(1u8), Wrapper(2i8));
+    let _ty_eq_array_len_different = ([0u8; 2], [0u8; 3]);
+    let first = async {};
+    let second = async {};
+    let _ty_eq_async_different = (first, second);
+
+    generic::<u8, u16>();
+}
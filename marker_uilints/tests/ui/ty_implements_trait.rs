@@ -0,0 +1,28 @@
+#![feature(trait_alias)]
+
+trait ImplCheck<T> {}
+trait ImplCheckAlias = ImplCheck<u8> + Copy;
+
+struct Wrapper<T>(T);
+
+impl ImplCheck<u8> for u16 {}
+impl<'a> ImplCheck<&'a str> for String {}
+impl<T> ImplCheck<T> for Wrapper<T> {}
+impl ImplCheck<u8> for [u8; 4] {}
+impl<T: Copy> ImplCheck<bool> for T {}
+
+fn main() {
+    let _impl_check_u16_u8 = (0u16, 0u8);
+    let _impl_check_u16_u32 = (0u16, 0u32);
+    let _impl_check_string_str = (String::new(), "");
+    let _impl_check_wrapper = (Wrapper(1u8), 1u8);
+    let _impl_check_wrapper_mismatch = (Wrapper(1u8), 1u16);
+    let _impl_check_no_args = (0u16,);
+    let _impl_check_array = ([0u8; 4], 0u8);
+    let _impl_check_array_len_mismatch = ([0u8; 2], 0u8);
+    let _impl_check_blanket = (0u64, false);
+    let _impl_check_blanket_unsatisfied = (String::new(), false);
+    let _impl_check_alias_u16 = (0u16,);
+    let _impl_check_alias_array = ([0u8; 4],);
+    let _impl_check_alias_string = (String::new(),);
+}